@@ -1,7 +1,7 @@
 use colorgrad::{BlendMode, CustomGradient, Interpolation};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-const COLORS: [&'static str; 104] = [
+const COLORS: [&str; 104] = [
     "#87e575", "#e88ef2", "#7398ef", "#65c3f2", "#3e52a0", "#b659db", "#75b7ff", "#7555ba",
     "#fceac4", "#e8009e", "#cc7c26", "#e175f4", "#f959e7", "#31828e", "#e4bef7", "#a9fcc6",
     "#c122d6", "#81f9e1", "#caea81", "#47d192", "#db579d", "#ead36b", "#3c2bbc", "#9de544",
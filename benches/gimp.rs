@@ -2,7 +2,7 @@ use colorgrad::Color;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use std::io::BufReader;
 
-const GGR_STR: &'static str = include_str!("../examples/ggr/My_Gradient.ggr");
+const GGR_STR: &str = include_str!("../examples/ggr/My_Gradient.ggr");
 
 const POSITIONS: [f64; 3] = [0.03, 0.5, 0.97];
 
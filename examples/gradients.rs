@@ -227,21 +227,21 @@ fn main() {
     }
 
     for (gradient, name) in preset_gradients {
-        let imgbuf = grad_rgb_plot(&gradient, width, height, padding);
+        let imgbuf = grad_rgb_plot(gradient, width, height, padding);
         let file_path = format!("example_output/preset_{}.png", name);
         println!("{}", file_path);
         imgbuf.save(file_path).unwrap();
     }
 
     for (gradient, name) in custom_gradients {
-        let imgbuf = grad_rgb_plot(&gradient, width, height, padding);
+        let imgbuf = grad_rgb_plot(gradient, width, height, padding);
         let file_path = format!("example_output/{}.png", name);
         println!("{}", file_path);
         imgbuf.save(file_path).unwrap();
     }
 
     for (gradient, name) in sharp_gradients {
-        let imgbuf = grad_rgb_plot(&gradient, width, height, padding);
+        let imgbuf = grad_rgb_plot(gradient, width, height, padding);
         let file_path = format!("example_output/sharp-smoothness-{}.png", name);
         println!("{}", file_path);
         imgbuf.save(file_path).unwrap();
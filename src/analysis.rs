@@ -0,0 +1,139 @@
+use crate::gradient::gamut::in_gamut;
+use crate::{from_linear_rgba, linspace, to_linear_rgba, Color, Gradient, LabSpace};
+
+/// Direction of a sequence of values
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Monotonicity {
+    /// Every value is larger than the previous one
+    Increasing,
+    /// Every value is smaller than the previous one
+    Decreasing,
+    /// All values are equal
+    Constant,
+    /// Not monotonic, or has flat parts
+    None,
+}
+
+impl Monotonicity {
+    fn of(values: &[f64]) -> Self {
+        const EPSILON: f64 = 1e-9;
+        let steps = || values.windows(2).map(|v| v[1] - v[0]);
+
+        if steps().all(|d| d > EPSILON) {
+            Self::Increasing
+        } else if steps().all(|d| d < -EPSILON) {
+            Self::Decreasing
+        } else if steps().all(|d| d.abs() <= EPSILON) {
+            Self::Constant
+        } else {
+            Self::None
+        }
+    }
+}
+
+/// The largest perceptual difference between two consecutive samples
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Jump {
+    pub start: f64,
+    pub end: f64,
+    pub delta_e: f64,
+}
+
+/// Colormap quality report, see [`Gradient::analyze`]
+///
+/// Lightness is measured in the chosen [`LabSpace`], perceptual differences with the
+/// matching metric: Oklab ΔE for [`LabSpace::Oklab`], CIEDE2000 for [`LabSpace::Cielab`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    pub space: LabSpace,
+    /// Evenly spaced sample positions across the domain
+    pub positions: Vec<f64>,
+    /// Lightness at each position
+    pub lightness: Vec<f64>,
+    pub lightness_monotonicity: Monotonicity,
+    /// Perceptual difference between each pair of consecutive samples
+    pub local_delta_e: Vec<f64>,
+    /// Perceptual distance from the start of the domain to each position
+    pub cumulative_delta_e: Vec<f64>,
+    pub max_jump: Jump,
+    /// `true` if every sample is inside the sRGB gamut
+    pub in_gamut: bool,
+    /// Perceptual difference between the colors at both ends of the domain
+    pub ends_delta_e: f64,
+    /// `true` if the colors at both ends of the domain are indistinguishable
+    pub cyclic: bool,
+    /// Lightness at each position after conversion to grayscale (relative luminance)
+    pub grayscale: Vec<f64>,
+    pub grayscale_monotonicity: Monotonicity,
+}
+
+impl Analysis {
+    pub(crate) fn new(gradient: &Gradient, n: usize, space: LabSpace) -> Self {
+        let metric = space.delta_e();
+        let (dmin, dmax) = gradient.domain();
+        let positions = linspace(dmin, dmax, n.max(2));
+        let colors: Vec<Color> = positions.iter().map(|&t| gradient.at(t)).collect();
+
+        let lightness: Vec<f64> = colors.iter().map(|c| space.lightness(c)).collect();
+        let grayscale: Vec<f64> = colors
+            .iter()
+            .map(|c| space.lightness(&to_grayscale(c)))
+            .collect();
+
+        let local_delta_e: Vec<f64> = colors
+            .windows(2)
+            .map(|c| metric.distance(&c[0], &c[1]))
+            .collect();
+
+        let mut cumulative_delta_e = Vec::with_capacity(colors.len());
+        let mut total = 0.0;
+        cumulative_delta_e.push(total);
+        for d in &local_delta_e {
+            total += d;
+            cumulative_delta_e.push(total);
+        }
+
+        let mut max_jump = Jump {
+            start: positions[0],
+            end: positions[1],
+            delta_e: local_delta_e[0],
+        };
+        for (i, &d) in local_delta_e.iter().enumerate() {
+            if d > max_jump.delta_e {
+                max_jump = Jump {
+                    start: positions[i],
+                    end: positions[i + 1],
+                    delta_e: d,
+                };
+            }
+        }
+
+        let ends_delta_e = metric.distance(&colors[0], &colors[colors.len() - 1]);
+
+        Self {
+            space,
+            lightness_monotonicity: Monotonicity::of(&lightness),
+            grayscale_monotonicity: Monotonicity::of(&grayscale),
+            in_gamut: colors.iter().all(in_gamut),
+            cyclic: ends_delta_e < metric.tolerance(),
+            positions,
+            lightness,
+            local_delta_e,
+            cumulative_delta_e,
+            max_jump,
+            ends_delta_e,
+            grayscale,
+        }
+    }
+
+    /// Total perceptual length of the gradient
+    pub fn total_delta_e(&self) -> f64 {
+        self.cumulative_delta_e[self.cumulative_delta_e.len() - 1]
+    }
+}
+
+fn to_grayscale(col: &Color) -> Color {
+    let [r, g, b, a] = to_linear_rgba(col);
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    from_linear_rgba(y, y, y, a)
+}
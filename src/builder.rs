@@ -1,8 +1,9 @@
 use std::{error, fmt};
 
 use crate::{
-    linspace, BasisGradient, BlendMode, CatmullRomGradient, Color, Gradient, GradientBase,
-    Interpolation, LinearGradient,
+    akima_tangents, linspace, monotone_tangents, natural_tangents, AlphaGradient, BasisGradient,
+    BlendMode, CatmullRomGradient, Color, Gradient, GradientBase, HardStopGradient,
+    HermiteGradient, Interpolation, LinearGradient, StepGradient, WideColor,
 };
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -79,6 +80,10 @@ pub struct CustomGradient {
     pos: Vec<f64>,
     mode: BlendMode,
     interpolation: Interpolation,
+    catmull_rom_alpha: f64,
+    catmull_rom_tension: f64,
+    alpha_interpolation: Option<Interpolation>,
+    opacity: Vec<(f64, f64)>,
     invalid_html_colors: Vec<String>,
 }
 
@@ -90,6 +95,10 @@ impl CustomGradient {
             pos: Vec::new(),
             mode: BlendMode::Rgb,
             interpolation: Interpolation::Linear,
+            catmull_rom_alpha: 0.5,
+            catmull_rom_tension: 0.0,
+            alpha_interpolation: None,
+            opacity: Vec::new(),
             invalid_html_colors: Vec::new(),
         }
     }
@@ -127,6 +136,16 @@ impl CustomGradient {
         self
     }
 
+    /// Set gradient color using colors in any supported color space, such as Display P3
+    /// or Rec. 2020. Colors outside the sRGB gamut are kept, use
+    /// [`Gradient::at_in()`] to get the output in a wide-gamut color space.
+    pub fn wide_colors<'a>(&'a mut self, colors: &[WideColor]) -> &'a mut Self {
+        for c in colors {
+            self.colors.push(c.to_color());
+        }
+        self
+    }
+
     /// Set the gradient domain and/or color position.
     pub fn domain<'a>(&'a mut self, pos: &[f64]) -> &'a mut Self {
         self.pos = pos.to_vec();
@@ -147,6 +166,98 @@ impl CustomGradient {
         self
     }
 
+    /// Set the Catmull-Rom spline parameters, only used by
+    /// [`Interpolation::CatmullRom`].
+    ///
+    /// * `alpha` - knot parameterization: `0.0` uniform, `0.5` centripetal (default),
+    ///   `1.0` chordal.
+    /// * `tension` - `0.0` (default) to `1.0`, higher value gives tighter curve.
+    ///
+    /// Both are clamped to `0.0..=1.0`, NaN gives the default value.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// use colorgrad::Interpolation;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let grad = colorgrad::CustomGradient::new()
+    ///     .html_colors(&["deeppink", "gold", "seagreen"])
+    ///     .interpolation(Interpolation::CatmullRom)
+    ///     .catmull_rom(1.0, 0.25)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::needless_lifetimes)]
+    pub fn catmull_rom<'a>(&'a mut self, alpha: f64, tension: f64) -> &'a mut Self {
+        self.catmull_rom_alpha = if alpha.is_nan() {
+            0.5
+        } else {
+            alpha.clamp(0.0, 1.0)
+        };
+        self.catmull_rom_tension = if tension.is_nan() {
+            0.0
+        } else {
+            tension.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Set the interpolation mode for the alpha channel. By default the alpha channel
+    /// uses the same interpolation as the color channels.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// use colorgrad::Interpolation;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let grad = colorgrad::CustomGradient::new()
+    ///     .html_colors(&["#f00", "#ff000000", "#0f0", "#00f"])
+    ///     .interpolation(Interpolation::CatmullRom)
+    ///     .alpha_interpolation(Interpolation::Linear)
+    ///     .build()?;
+    ///
+    /// assert_eq!(grad.at(1.0 / 6.0).a, 0.5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::needless_lifetimes)]
+    pub fn alpha_interpolation<'a>(&'a mut self, mode: Interpolation) -> &'a mut Self {
+        self.alpha_interpolation = Some(mode);
+        self
+    }
+
+    /// Set the opacity stops as `(position, alpha)`, independent of the color stops.
+    /// The alpha of the colors is ignored when the opacity stops is set. Two stops at
+    /// the same position make a hard stop.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let grad = colorgrad::CustomGradient::new()
+    ///     .html_colors(&["deeppink", "gold", "seagreen"])
+    ///     .opacity(&[(0.0, 1.0), (0.3, 0.0), (1.0, 1.0)])
+    ///     .build()?;
+    ///
+    /// assert_eq!(grad.at(0.3).a, 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn opacity<'a>(&'a mut self, stops: &[(f64, f64)]) -> &'a mut Self {
+        self.opacity = stops.to_vec();
+        self
+    }
+
+    /// Get the colors added so far
+    pub fn get_colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Get the domain and/or color positions set with [`CustomGradient::domain()`]
+    pub fn get_domain(&self) -> &[f64] {
+        &self.pos
+    }
+
     /// Build the gradient
     pub fn build(&self) -> Result<Gradient, CustomGradientError> {
         if !self.invalid_html_colors.is_empty() {
@@ -193,10 +304,39 @@ impl CustomGradient {
         let dmin = pos[0];
         let dmax = pos[pos.len() - 1];
 
-        let gradient: Box<dyn GradientBase + Send + Sync> = match self.interpolation {
-            Interpolation::Linear => Box::new(LinearGradient::new(colors, pos, mode)),
-            Interpolation::CatmullRom => Box::new(CatmullRomGradient::new(colors, pos, mode)),
-            Interpolation::Basis => Box::new(BasisGradient::new(colors, pos, mode)),
+        let alpha = if !self.opacity.is_empty() {
+            for p in self.opacity.windows(2) {
+                if p[0].0 > p[1].0 {
+                    return Err(CustomGradientError::WrongDomain);
+                }
+            }
+            let (mut alpha_pos, mut alphas): (Vec<_>, Vec<_>) =
+                self.opacity.iter().copied().unzip();
+            if alphas.len() == 1 {
+                alphas.push(alphas[0]);
+                alpha_pos = vec![dmin, dmax];
+            } else if alpha_pos[0] == alpha_pos[alpha_pos.len() - 1] {
+                return Err(CustomGradientError::WrongDomain);
+            }
+            Some((alphas, alpha_pos))
+        } else if self.alpha_interpolation.is_some() {
+            Some((colors.iter().map(|c| c.a).collect(), pos.clone()))
+        } else {
+            None
+        };
+
+        let gradient = self.build_runs(colors, pos, mode, self.interpolation);
+
+        let gradient = if let Some((alphas, alpha_pos)) = alpha {
+            let alpha_colors = alphas
+                .iter()
+                .map(|a| Color::new(0.0, 0.0, 0.0, *a))
+                .collect();
+            let interpolation = self.alpha_interpolation.unwrap_or(self.interpolation);
+            let alpha = self.build_runs(alpha_colors, alpha_pos, BlendMode::Rgb, interpolation);
+            Box::new(AlphaGradient::new(gradient, alpha))
+        } else {
+            gradient
         };
 
         Ok(Gradient {
@@ -205,4 +345,88 @@ impl CustomGradient {
             dmax,
         })
     }
+
+    // Build the gradient, splitting it at every hard stop. When all the positions are
+    // equal there is nothing to split, the first color is used up to that position and
+    // the last color after it.
+    fn build_runs(
+        &self,
+        colors: Vec<Color>,
+        pos: Vec<f64>,
+        mode: BlendMode,
+        interpolation: Interpolation,
+    ) -> Box<dyn GradientBase + Send + Sync> {
+        let dmin = pos[0];
+        let dmax = pos[pos.len() - 1];
+        if dmin < dmax && pos.windows(2).any(|p| p[0] == p[1]) {
+            let first_color = colors[0].clone();
+            let last_color = colors[colors.len() - 1].clone();
+            let mut runs = Vec::new();
+            let mut start = 0;
+
+            for i in 1..=pos.len() {
+                if i < pos.len() && pos[i] != pos[i - 1] {
+                    continue;
+                }
+                let gradbase: Box<dyn GradientBase + Send + Sync> = if i - start == 1 {
+                    // Single color between two hard stops
+                    let col = colors[start].clone();
+                    let p = pos[start];
+                    Box::new(LinearGradient::new(
+                        vec![col.clone(), col],
+                        vec![p, p],
+                        mode,
+                    ))
+                } else {
+                    self.build_base(
+                        colors[start..i].to_vec(),
+                        pos[start..i].to_vec(),
+                        mode,
+                        interpolation,
+                    )
+                };
+                runs.push((pos[start], gradbase));
+                start = i;
+            }
+
+            Box::new(HardStopGradient::new(
+                runs,
+                (dmin, dmax),
+                first_color,
+                last_color,
+            ))
+        } else {
+            self.build_base(colors, pos, mode, interpolation)
+        }
+    }
+
+    fn build_base(
+        &self,
+        colors: Vec<Color>,
+        pos: Vec<f64>,
+        mode: BlendMode,
+        interpolation: Interpolation,
+    ) -> Box<dyn GradientBase + Send + Sync> {
+        match interpolation {
+            Interpolation::Linear => Box::new(LinearGradient::new(colors, pos, mode)),
+            Interpolation::CatmullRom => Box::new(CatmullRomGradient::new(
+                colors,
+                pos,
+                mode,
+                self.catmull_rom_alpha,
+                self.catmull_rom_tension,
+            )),
+            Interpolation::Basis => Box::new(BasisGradient::new(colors, pos, mode)),
+            Interpolation::MonotoneCubic => {
+                Box::new(HermiteGradient::new(colors, pos, mode, monotone_tangents))
+            }
+            Interpolation::Akima => {
+                Box::new(HermiteGradient::new(colors, pos, mode, akima_tangents))
+            }
+            Interpolation::NaturalCubic => {
+                Box::new(HermiteGradient::new(colors, pos, mode, natural_tangents))
+            }
+            Interpolation::Step(step) => Box::new(StepGradient::new(colors, pos, step)),
+        }
+    }
 }
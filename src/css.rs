@@ -0,0 +1,757 @@
+//! Parse CSS gradient functions
+//!
+//! [`parse()`] reads `linear-gradient()`, `radial-gradient()`, `conic-gradient()` and
+//! their `repeating-` variants as defined in CSS Images Level 4. The color stop list
+//! becomes a [`Gradient`] over the gradient line, where `0` is the start and `1` the
+//! end (`0%` and `100%`), and the geometry is returned separately.
+//!
+//! Supported are double-position color stops, color hints, and the interpolation
+//! color space (`in oklab`, `in oklch longer hue`, ...). Colors are interpolated with
+//! premultiplied alpha like browsers do, in sRGB when no color space is given.
+//!
+//! Color stop positions must be percentages (or angles for `conic-gradient()`).
+//! Lengths like `10px` depend on the size of the box and are not supported.
+//!
+//! ```
+//! use colorgrad::css::{self, Direction, Geometry};
+//! use colorgrad::Spread;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let g = css::parse("linear-gradient(to right in oklab, red 20%, gold, #00f)")?;
+//!
+//! assert_eq!(g.geometry, Geometry::Linear(Direction::Angle(90.0)));
+//! assert_eq!(g.spread, Spread::Pad);
+//! assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+//! assert_eq!(g.at(0.6).to_hex_string(), "#ffd700");
+//!
+//! let g = css::parse("repeating-linear-gradient(45deg, #000 0% 10%, #fff 10% 20%)")?;
+//! assert_eq!(g.spread, Spread::Repeat);
+//! assert_eq!(g.at(0.25).to_hex_string(), "#000000");
+//! assert_eq!(g.at(0.35).to_hex_string(), "#ffffff");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{error, fmt};
+
+use crate::spec::split_top_level;
+use crate::{Color, CssSpace, CssStopsGradient, Gradient, HueMethod, ParseColorError, Spread};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ParseError {
+    /// Not a `linear-gradient()`, `radial-gradient()` or `conic-gradient()`
+    UnknownFunction(String),
+    InvalidGeometry(String),
+    /// Interpolation color space that is not supported, e.g. `a98-rgb`
+    UnsupportedColorSpace(String),
+    /// Lengths relative to the font or viewport, and color stop positions in lengths
+    UnsupportedUnit(String),
+    InvalidColor(String, ParseColorError),
+    InvalidStop(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::UnknownFunction(ref s) => write!(f, "unknown gradient function '{}'", s),
+            Self::InvalidGeometry(ref s) => write!(f, "invalid gradient geometry '{}'", s),
+            Self::UnsupportedColorSpace(ref s) => {
+                write!(f, "unsupported interpolation color space '{}'", s)
+            }
+            Self::UnsupportedUnit(ref s) => write!(f, "unsupported unit in '{}'", s),
+            Self::InvalidColor(ref s, ref e) => write!(f, "invalid color '{}': {}", s, e),
+            Self::InvalidStop(ref s) => write!(f, "invalid color stop '{}'", s),
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Self::InvalidColor(_, ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Length, in CSS pixels or as a percentage of the box
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Length {
+    Px(f64),
+    /// Percentage, `50%` is `Percent(50.0)`
+    Percent(f64),
+}
+
+/// Position in the box, e.g. the center of a radial gradient
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Position {
+    pub x: Length,
+    pub y: Length,
+}
+
+impl Position {
+    pub const CENTER: Self = Self {
+        x: Length::Percent(50.0),
+        y: Length::Percent(50.0),
+    };
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+/// Direction of a linear gradient
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Direction {
+    /// Angle in degrees, clockwise from the top: `0` is `to top`, `90` is `to right`
+    Angle(f64),
+    /// `to top right` and the like, the angle depends on the size of the box
+    Corner(Corner),
+}
+
+impl Direction {
+    /// Angle in degrees for a box of `width` × `height`
+    ///
+    /// ```
+    /// use colorgrad::css::{Corner, Direction};
+    ///
+    /// assert_eq!(Direction::Angle(30.0).angle(200.0, 100.0), 30.0);
+    /// assert_eq!(Direction::Corner(Corner::TopRight).angle(100.0, 100.0), 45.0);
+    /// ```
+    pub fn angle(&self, width: f64, height: f64) -> f64 {
+        let a = match *self {
+            Self::Angle(a) => return a,
+            Self::Corner(_) => height.atan2(width).to_degrees(),
+        };
+        // The line through the middle is the diagonal between the other two corners
+        match *self {
+            Self::Corner(Corner::TopRight) => a,
+            Self::Corner(Corner::BottomRight) => 180.0 - a,
+            Self::Corner(Corner::BottomLeft) => 180.0 + a,
+            _ => 360.0 - a,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Shape {
+    Circle,
+    Ellipse,
+}
+
+/// Size of the ending shape of a radial gradient
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Size {
+    ClosestSide,
+    ClosestCorner,
+    FarthestSide,
+    FarthestCorner,
+    /// Radius of a circle
+    Radius(Length),
+    /// Horizontal and vertical radii of an ellipse
+    Radii(Length, Length),
+}
+
+/// Geometry of a CSS gradient
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Geometry {
+    Linear(Direction),
+    Radial {
+        shape: Shape,
+        size: Size,
+        center: Position,
+    },
+    /// `from` is the starting angle in degrees, clockwise from the top
+    Conic {
+        from: f64,
+        center: Position,
+    },
+}
+
+/// Parsed CSS gradient
+#[derive(Debug)]
+pub struct CssGradient {
+    /// Colors along the gradient line, from `0` (`0%`) to `1` (`100%`)
+    pub gradient: Gradient,
+    pub geometry: Geometry,
+    /// [`Spread::Repeat`] for the `repeating-` functions, [`Spread::Pad`] otherwise
+    pub spread: Spread,
+}
+
+impl CssGradient {
+    /// Get color at certain position of the gradient line, using the spread
+    pub fn at(&self, t: f64) -> Color {
+        self.gradient.spread_at(t, self.spread)
+    }
+}
+
+// Number and lowercase unit, e.g. "10.5%" is (10.5, "%") and "1e1px" is (10.0, "px")
+fn split_unit(s: &str) -> Option<(f64, String)> {
+    let b = s.as_bytes();
+    let digits = |mut i: usize| {
+        while i < b.len() && b[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut i = 0;
+    if i < b.len() && (b[i] == b'+' || b[i] == b'-') {
+        i += 1;
+    }
+    i = digits(i);
+    if i < b.len() && b[i] == b'.' {
+        i = digits(i + 1);
+    }
+    // An exponent needs digits, otherwise the "e" starts a unit (e.g. "em")
+    if i < b.len() && (b[i] == b'e' || b[i] == b'E') {
+        let j = if matches!(b.get(i + 1), Some(b'+' | b'-')) {
+            i + 2
+        } else {
+            i + 1
+        };
+        if b.get(j).map_or(false, u8::is_ascii_digit) {
+            i = digits(j);
+        }
+    }
+
+    let value = s[..i].parse::<f64>().ok().filter(|v| v.is_finite())?;
+    Some((value, s[i..].to_ascii_lowercase()))
+}
+
+// Angle in degrees
+fn parse_angle(s: &str) -> Option<f64> {
+    let (v, unit) = split_unit(s)?;
+    match unit.as_str() {
+        "deg" => Some(v),
+        "grad" => Some(v * 0.9),
+        "rad" => Some(v.to_degrees()),
+        "turn" => Some(v * 360.0),
+        "" if v == 0.0 => Some(0.0),
+        _ => None,
+    }
+}
+
+fn is_relative_unit(unit: &str) -> bool {
+    matches!(
+        unit,
+        "em" | "rem" | "ex" | "rex" | "ch" | "rch" | "ic" | "ric" | "cap" | "rcap" | "lh" | "rlh"
+    ) || unit.ends_with("vw")
+        || unit.ends_with("vh")
+        || unit.ends_with("vi")
+        || unit.ends_with("vb")
+        || unit.ends_with("vmin")
+        || unit.ends_with("vmax")
+        || unit.starts_with("cq")
+}
+
+fn parse_length(s: &str) -> Result<Length, ParseError> {
+    let (v, unit) = split_unit(s).ok_or_else(|| ParseError::InvalidGeometry(s.to_string()))?;
+    let px = match unit.as_str() {
+        "%" => return Ok(Length::Percent(v)),
+        "" if v == 0.0 => 0.0,
+        "px" => v,
+        "in" => v * 96.0,
+        "cm" => v * 96.0 / 2.54,
+        "mm" => v * 96.0 / 25.4,
+        "q" => v * 96.0 / 101.6,
+        "pt" => v * 96.0 / 72.0,
+        "pc" => v * 16.0,
+        u if is_relative_unit(u) => return Err(ParseError::UnsupportedUnit(s.to_string())),
+        _ => return Err(ParseError::InvalidGeometry(s.to_string())),
+    };
+    Ok(Length::Px(px))
+}
+
+fn parse_space(s: &str) -> Result<CssSpace, ParseError> {
+    match s {
+        "srgb" => Ok(CssSpace::Srgb),
+        "srgb-linear" | "xyz" | "xyz-d50" | "xyz-d65" => Ok(CssSpace::SrgbLinear),
+        "display-p3" => Ok(CssSpace::DisplayP3),
+        "rec2020" => Ok(CssSpace::Rec2020),
+        "lab" => Ok(CssSpace::Lab),
+        "oklab" => Ok(CssSpace::Oklab),
+        "hsl" => Ok(CssSpace::Hsl),
+        "hwb" => Ok(CssSpace::Hwb),
+        "lch" => Ok(CssSpace::Lch),
+        "oklch" => Ok(CssSpace::Oklch),
+        _ => Err(ParseError::UnsupportedColorSpace(s.to_string())),
+    }
+}
+
+fn parse_direction(tokens: &[String]) -> Option<Direction> {
+    let sides: Vec<&str> = match tokens {
+        [] => return Some(Direction::Angle(180.0)),
+        [angle] => return parse_angle(angle).map(Direction::Angle),
+        [to, rest @ ..] if to == "to" => rest.iter().map(String::as_str).collect(),
+        _ => return None,
+    };
+    match sides.as_slice() {
+        ["top"] => Some(Direction::Angle(0.0)),
+        ["right"] => Some(Direction::Angle(90.0)),
+        ["bottom"] => Some(Direction::Angle(180.0)),
+        ["left"] => Some(Direction::Angle(270.0)),
+        ["top", "left"] | ["left", "top"] => Some(Direction::Corner(Corner::TopLeft)),
+        ["top", "right"] | ["right", "top"] => Some(Direction::Corner(Corner::TopRight)),
+        ["bottom", "right"] | ["right", "bottom"] => Some(Direction::Corner(Corner::BottomRight)),
+        ["bottom", "left"] | ["left", "bottom"] => Some(Direction::Corner(Corner::BottomLeft)),
+        _ => None,
+    }
+}
+
+enum Coord {
+    X(Length),
+    Y(Length),
+    Center,
+    Any(Length),
+}
+
+// The one- and two-value forms of <position>
+fn parse_position(tokens: &[String]) -> Result<Option<Position>, ParseError> {
+    let coord = |s: &str| -> Result<Coord, ParseError> {
+        Ok(match s {
+            "left" => Coord::X(Length::Percent(0.0)),
+            "right" => Coord::X(Length::Percent(100.0)),
+            "top" => Coord::Y(Length::Percent(0.0)),
+            "bottom" => Coord::Y(Length::Percent(100.0)),
+            "center" => Coord::Center,
+            _ => Coord::Any(parse_length(s)?),
+        })
+    };
+    let center = Length::Percent(50.0);
+
+    let (x, y) = match tokens {
+        [a] => match coord(a)? {
+            Coord::X(x) | Coord::Any(x) => (x, center),
+            Coord::Y(y) => (center, y),
+            Coord::Center => (center, center),
+        },
+        [a, b] => match (coord(a)?, coord(b)?) {
+            (Coord::X(x) | Coord::Any(x), Coord::Y(y) | Coord::Any(y)) => (x, y),
+            (Coord::Y(y), Coord::X(x)) => (x, y),
+            (Coord::X(x), Coord::Center) | (Coord::Center, Coord::X(x)) => (x, center),
+            (Coord::Y(y), Coord::Center) | (Coord::Center, Coord::Y(y)) => (center, y),
+            (Coord::Center, Coord::Center) => (center, center),
+            (Coord::Any(x), Coord::Center) => (x, center),
+            (Coord::Center, Coord::Any(y)) => (center, y),
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(Position { x, y }))
+}
+
+fn parse_radial(tokens: &[String]) -> Result<Option<Geometry>, ParseError> {
+    let at = tokens.iter().position(|t| t == "at");
+    let (ending, center) = match at {
+        Some(i) => match parse_position(&tokens[i + 1..])? {
+            Some(pos) => (&tokens[..i], pos),
+            None => return Ok(None),
+        },
+        None => (tokens, Position::CENTER),
+    };
+
+    let mut shape = None;
+    let mut keyword = None;
+    let mut lengths = Vec::new();
+
+    for t in ending {
+        match t.as_str() {
+            "circle" | "ellipse" if shape.is_none() => {
+                shape = Some(if t == "circle" {
+                    Shape::Circle
+                } else {
+                    Shape::Ellipse
+                });
+            }
+            "closest-side" if keyword.is_none() => keyword = Some(Size::ClosestSide),
+            "closest-corner" if keyword.is_none() => keyword = Some(Size::ClosestCorner),
+            "farthest-side" if keyword.is_none() => keyword = Some(Size::FarthestSide),
+            "farthest-corner" if keyword.is_none() => keyword = Some(Size::FarthestCorner),
+            _ => lengths.push(parse_length(t)?),
+        }
+    }
+
+    let negative = |l: &Length| match *l {
+        Length::Px(v) | Length::Percent(v) => v < 0.0,
+    };
+    if lengths.iter().any(negative) {
+        return Ok(None);
+    }
+
+    let (shape, size) = match (shape, keyword, lengths.as_slice()) {
+        (shape, Some(size), []) => (shape.unwrap_or(Shape::Ellipse), size),
+        (None | Some(Shape::Ellipse), None, []) => (Shape::Ellipse, Size::FarthestCorner),
+        (Some(Shape::Circle), None, []) => (Shape::Circle, Size::FarthestCorner),
+        // A circle can't be sized by a percentage
+        (None | Some(Shape::Circle), None, [r @ Length::Px(_)]) => {
+            (Shape::Circle, Size::Radius(*r))
+        }
+        (None | Some(Shape::Ellipse), None, [rx, ry]) => (Shape::Ellipse, Size::Radii(*rx, *ry)),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(Geometry::Radial {
+        shape,
+        size,
+        center,
+    }))
+}
+
+fn parse_conic(tokens: &[String]) -> Result<Option<Geometry>, ParseError> {
+    let (from, rest) = match tokens {
+        [f, angle, rest @ ..] if f == "from" => match parse_angle(angle) {
+            Some(a) => (a, rest),
+            None => return Ok(None),
+        },
+        _ => (0.0, tokens),
+    };
+    let center = match rest {
+        [] => Position::CENTER,
+        [at, pos @ ..] if at == "at" => match parse_position(pos)? {
+            Some(pos) => pos,
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(Geometry::Conic { from, center }))
+}
+
+// Whether the first token of the arguments starts the geometry or the interpolation
+// method: a keyword of the gradient function, an angle or a length
+fn is_prelude(function: &str, token: &str) -> bool {
+    let keyword = match function {
+        "linear-gradient" => matches!(token, "to" | "in"),
+        "radial-gradient" => matches!(
+            token,
+            "in" | "at"
+                | "circle"
+                | "ellipse"
+                | "closest-side"
+                | "closest-corner"
+                | "farthest-side"
+                | "farthest-corner"
+        ),
+        _ => matches!(token, "in" | "from" | "at"),
+    };
+    keyword || token.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '+' | '-' | '.'))
+}
+
+// The part before the color stops: geometry and interpolation method, in any order
+fn parse_prelude(function: &str, s: &str) -> Result<(Geometry, CssSpace, HueMethod), ParseError> {
+    let invalid = || ParseError::InvalidGeometry(s.to_string());
+    let mut tokens: Vec<String> = split_top_level(s, char::is_whitespace)
+        .into_iter()
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_ascii_lowercase())
+        .collect();
+
+    let mut space = CssSpace::Srgb;
+    let mut hue = HueMethod::Shorter;
+
+    if let Some(i) = tokens.iter().position(|t| t == "in") {
+        space = parse_space(tokens.get(i + 1).ok_or_else(invalid)?)?;
+        let mut end = i + 2;
+        if tokens.get(i + 3).map(String::as_str) == Some("hue") {
+            if space.hue_index().is_none() {
+                return Err(invalid());
+            }
+            hue = match tokens[i + 2].as_str() {
+                "shorter" => HueMethod::Shorter,
+                "longer" => HueMethod::Longer,
+                "increasing" => HueMethod::Increasing,
+                "decreasing" => HueMethod::Decreasing,
+                _ => return Err(invalid()),
+            };
+            end = i + 4;
+        }
+        tokens.drain(i..end);
+    }
+
+    let geometry = match function {
+        "linear-gradient" => parse_direction(&tokens).map(Geometry::Linear),
+        "radial-gradient" => parse_radial(&tokens)?,
+        _ => parse_conic(&tokens)?,
+    };
+
+    Ok((geometry.ok_or_else(invalid)?, space, hue))
+}
+
+// Position on the gradient line, as a fraction
+fn parse_stop_position(s: &str, conic: bool) -> Result<f64, ParseError> {
+    let invalid = || ParseError::InvalidStop(s.to_string());
+    let (v, unit) = split_unit(s).ok_or_else(invalid)?;
+    match unit.as_str() {
+        "%" => Ok(v / 100.0),
+        "" if v == 0.0 => Ok(0.0),
+        _ if conic => parse_angle(s).map(|a| a / 360.0).ok_or_else(invalid),
+        _ if parse_length(s).is_ok() || is_relative_unit(&unit) => {
+            Err(ParseError::UnsupportedUnit(s.to_string()))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+enum Item {
+    Stop(Color, Option<f64>),
+    Hint(f64),
+}
+
+fn parse_stops(args: &[&str], conic: bool) -> Result<Vec<Item>, ParseError> {
+    let mut items = Vec::new();
+
+    for arg in args {
+        let tokens: Vec<&str> = split_top_level(arg, char::is_whitespace)
+            .into_iter()
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        match tokens.as_slice() {
+            [token] => match csscolorparser::parse(token) {
+                Ok(col) => items.push(Item::Stop(col, None)),
+                Err(e) => match parse_stop_position(token, conic) {
+                    Ok(pos) => items.push(Item::Hint(pos)),
+                    Err(ParseError::InvalidStop(_)) => {
+                        return Err(ParseError::InvalidColor(token.to_string(), e))
+                    }
+                    Err(e) => return Err(e),
+                },
+            },
+            [color, positions @ ..] if positions.len() <= 2 => {
+                let col = csscolorparser::parse(color)
+                    .map_err(|e| ParseError::InvalidColor(color.to_string(), e))?;
+                for pos in positions {
+                    let pos = parse_stop_position(pos, conic)?;
+                    items.push(Item::Stop(col.clone(), Some(pos)));
+                }
+            }
+            _ => return Err(ParseError::InvalidStop(arg.to_string())),
+        }
+    }
+
+    // A color hint must be between two color stops
+    let is_hint = |i: Option<&Item>| matches!(i, Some(Item::Hint(_)));
+    for (i, item) in items.iter().enumerate() {
+        if let Item::Hint(_) = item {
+            if i == 0 || i + 1 == items.len() || is_hint(items.get(i + 1)) {
+                return Err(ParseError::InvalidStop(args.join(", ")));
+            }
+        }
+    }
+
+    if items.is_empty() {
+        return Err(ParseError::InvalidStop(String::new()));
+    }
+
+    Ok(items)
+}
+
+// Resolve the missing and out of order positions, as in CSS Images 4
+// https://www.w3.org/TR/css-images-4/#color-stop-fixup
+fn fix_positions(items: &mut [Item]) {
+    let n = items.len();
+    if let Item::Stop(_, ref mut pos @ None) = items[0] {
+        *pos = Some(0.0);
+    }
+    if let Item::Stop(_, ref mut pos @ None) = items[n - 1] {
+        *pos = Some(1.0);
+    }
+
+    let mut max = f64::NEG_INFINITY;
+    for item in items.iter_mut() {
+        let pos = match item {
+            Item::Stop(_, Some(pos)) | Item::Hint(pos) => pos,
+            _ => continue,
+        };
+        max = max.max(*pos);
+        *pos = max;
+    }
+
+    // Spread runs of color stops without positions evenly, hints are not counted
+    let stops: Vec<usize> = (0..n)
+        .filter(|&i| matches!(items[i], Item::Stop(..)))
+        .collect();
+    let position = |item: &Item| match *item {
+        Item::Stop(_, pos) => pos,
+        Item::Hint(pos) => Some(pos),
+    };
+
+    let mut k = 0;
+    while k < stops.len() {
+        if position(&items[stops[k]]).is_some() {
+            k += 1;
+            continue;
+        }
+        let start = k - 1;
+        let mut end = k;
+        while position(&items[stops[end]]).is_none() {
+            end += 1;
+        }
+        let p0 = position(&items[stops[start]]).unwrap();
+        let p1 = position(&items[stops[end]]).unwrap();
+        for j in k..end {
+            if let Item::Stop(_, ref mut pos) = items[stops[j]] {
+                *pos = Some(p0 + (p1 - p0) * (j - start) as f64 / (end - start) as f64);
+            }
+        }
+        k = end;
+    }
+}
+
+/// Parse CSS gradient function
+///
+/// ```
+/// use colorgrad::css::{self, Geometry, Length, Position, Shape, Size};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let g = css::parse("radial-gradient(circle 40px at top left, gold, 30%, transparent)")?;
+///
+/// assert_eq!(
+///     g.geometry,
+///     Geometry::Radial {
+///         shape: Shape::Circle,
+///         size: Size::Radius(Length::Px(40.0)),
+///         center: Position {
+///             x: Length::Percent(0.0),
+///             y: Length::Percent(0.0),
+///         },
+///     }
+/// );
+/// // The color hint moves the midpoint of gold and transparent to 30%
+/// assert_eq!(g.at(0.3).to_rgba8(), [255, 215, 0, 128]);
+///
+/// let err = css::parse("linear-gradient(45deg, red, blue 10px)").unwrap_err();
+/// assert_eq!(err, css::ParseError::UnsupportedUnit("10px".to_string()));
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse(s: &str) -> Result<CssGradient, ParseError> {
+    let s = s.trim();
+    let (function, args) = match (s.find('('), s.strip_suffix(')')) {
+        (Some(i), Some(inner)) => (s[..i].trim().to_ascii_lowercase(), &inner[i + 1..]),
+        _ => return Err(ParseError::UnknownFunction(s.to_string())),
+    };
+
+    let (name, spread) = match function.strip_prefix("repeating-") {
+        Some(name) => (name, Spread::Repeat),
+        None => (function.as_str(), Spread::Pad),
+    };
+    if !matches!(
+        name,
+        "linear-gradient" | "radial-gradient" | "conic-gradient"
+    ) {
+        return Err(ParseError::UnknownFunction(function));
+    }
+
+    let mut args: Vec<&str> = split_top_level(args, |c| c == ',')
+        .into_iter()
+        .map(str::trim)
+        .collect();
+
+    // The geometry starts with a keyword or a number, anything else is a color stop
+    let first = split_top_level(args[0], char::is_whitespace)[0].to_ascii_lowercase();
+    let (geometry, space, hue) = if is_prelude(name, &first) {
+        parse_prelude(name, args.remove(0))?
+    } else {
+        let geometry = match name {
+            "linear-gradient" => Geometry::Linear(Direction::Angle(180.0)),
+            "radial-gradient" => Geometry::Radial {
+                shape: Shape::Ellipse,
+                size: Size::FarthestCorner,
+                center: Position::CENTER,
+            },
+            _ => Geometry::Conic {
+                from: 0.0,
+                center: Position::CENTER,
+            },
+        };
+        (geometry, CssSpace::Srgb, HueMethod::Shorter)
+    };
+
+    let mut items = parse_stops(&args, name == "conic-gradient")?;
+    fix_positions(&mut items);
+
+    let mut colors = Vec::new();
+    let mut positions = Vec::new();
+    let mut hints = Vec::new();
+    let mut hint = None;
+
+    for item in items {
+        match item {
+            Item::Stop(col, pos) => {
+                if !colors.is_empty() {
+                    hints.push(hint.take());
+                }
+                colors.push(col);
+                positions.push(pos.unwrap_or_default());
+            }
+            Item::Hint(pos) => hint = Some(pos),
+        }
+    }
+
+    let first = positions[0];
+    let last = positions[positions.len() - 1];
+
+    if spread == Spread::Repeat && last - first <= f64::EPSILON {
+        // Zero-length repeating gradient: a solid color, the average of the gradient
+        // with the color stops evenly spaced. The color hints are all at the same
+        // position, they have no meaning between the spaced out stops.
+        let n = colors.len();
+        let positions = (0..n).map(|i| i as f64 / (n - 1).max(1) as f64).collect();
+        let hints = vec![None; n - 1];
+        let even = Gradient {
+            gradient: Box::new(CssStopsGradient::new(colors, positions, hints, space, hue)),
+            dmin: 0.0,
+            dmax: 1.0,
+        };
+        let samples = 256;
+        let mut sum = [0.0; 4];
+        for i in 0..samples {
+            let c = even.at((i as f64 + 0.5) / samples as f64);
+            for (s, v) in sum.iter_mut().zip([c.r * c.a, c.g * c.a, c.b * c.a, c.a]) {
+                *s += v / samples as f64;
+            }
+        }
+        let [r, g, b, a] = sum;
+        let col = if a > 0.0 {
+            Color::new(r / a, g / a, b / a, a)
+        } else {
+            Color::new(0.0, 0.0, 0.0, 0.0)
+        };
+        let gradient = Gradient {
+            gradient: Box::new(CssStopsGradient::new(
+                vec![col],
+                vec![0.0],
+                Vec::new(),
+                CssSpace::Srgb,
+                HueMethod::Shorter,
+            )),
+            dmin: 0.0,
+            dmax: 1.0,
+        };
+        return Ok(CssGradient {
+            gradient,
+            geometry,
+            spread,
+        });
+    }
+
+    let (dmin, dmax) = match spread {
+        Spread::Repeat => (first, last),
+        _ => (first.min(0.0), last.max(1.0)),
+    };
+
+    Ok(CssGradient {
+        gradient: Gradient {
+            gradient: Box::new(CssStopsGradient::new(colors, positions, hints, space, hue)),
+            dmin,
+            dmax,
+        },
+        geometry,
+        spread,
+    })
+}
@@ -0,0 +1,123 @@
+use crate::{Gradient, HelixGradient};
+
+/// Build a cubehelix gradient from the parameters of D. A. Green (2011),
+/// ["A colour scheme for the display of astronomical intensity images"](https://arxiv.org/abs/1108.5083).
+///
+/// Lightness increases monotonically (when `gamma` is 1.0 the perceived brightness of
+/// the gray it winds around is linear), while the hue rotates around the gray diagonal of
+/// the RGB cube. Colors are clipped into [0..1] like the reference implementations.
+///
+/// The defaults are Green's default scheme (`start` 0.5, `rotations` -1.5, `hue` 1.0,
+/// `gamma` 1.0, lightness 0.0 to 1.0), the same as matplotlib's `cubehelix` colormap.
+///
+/// # Examples
+///
+/// ```
+/// // matplotlib's cubehelix(start=0.5, rot=-1.5, gamma=1.0, hue=1.0)
+/// let grad = colorgrad::CubehelixGradient::new().build();
+///
+/// assert_eq!(grad.at(0.0).to_rgba8(), [0, 0, 0, 255]);
+/// assert_eq!(grad.at(1.0).to_rgba8(), [255, 255, 255, 255]);
+///
+/// // Cyan to red, not reaching black or white
+/// let grad = colorgrad::CubehelixGradient::new()
+///     .start(2.0)
+///     .rotations(1.0)
+///     .hue(1.5)
+///     .gamma(0.8)
+///     .lightness(0.2, 0.9)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CubehelixGradient {
+    start: f64,
+    rotations: f64,
+    hue: (f64, f64),
+    gamma: f64,
+    lightness: (f64, f64),
+    clamp_terms: bool,
+}
+
+impl CubehelixGradient {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            start: 0.5,
+            rotations: -1.5,
+            hue: (1.0, 1.0),
+            gamma: 1.0,
+            lightness: (0.0, 1.0),
+            clamp_terms: false,
+        }
+    }
+
+    /// Set the starting hue, `0.0` to `3.0`: 1.0 is red, 2.0 is green and 3.0 (or 0.0)
+    /// is blue.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn start<'a>(&'a mut self, start: f64) -> &'a mut Self {
+        self.start = start;
+        self
+    }
+
+    /// Set the number of R -> G -> B rotations from the start to the end of the
+    /// gradient. Negative values rotate in the opposite direction.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn rotations<'a>(&'a mut self, rotations: f64) -> &'a mut Self {
+        self.rotations = rotations;
+        self
+    }
+
+    /// Set the hue (saturation) parameter. `0.0` is pure grayscale, values above
+    /// `1.0` may get clipped at some lightness.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn hue<'a>(&'a mut self, hue: f64) -> &'a mut Self {
+        self.hue = (hue, hue);
+        self
+    }
+
+    /// Set the hue (saturation) parameter at the start and the end of the gradient, it
+    /// changes linearly in between.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn hue_range<'a>(&'a mut self, start: f64, end: f64) -> &'a mut Self {
+        self.hue = (start, end);
+        self
+    }
+
+    /// Set the gamma factor. Values below `1.0` emphasize the low intensities, values
+    /// above `1.0` the high intensities. Must be greater than zero.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn gamma<'a>(&'a mut self, gamma: f64) -> &'a mut Self {
+        self.gamma = gamma;
+        self
+    }
+
+    /// Set the lightness at the start and the end of the gradient, in [0..1].
+    /// `min > max` makes a gradient from light to dark.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn lightness<'a>(&'a mut self, min: f64, max: f64) -> &'a mut Self {
+        self.lightness = (min, max);
+        self
+    }
+
+    // Cap the red and green deviation terms at 1.0 like D3, used by the presets
+    pub(crate) fn clamp_terms(&mut self) -> &mut Self {
+        self.clamp_terms = true;
+        self
+    }
+
+    /// Build the gradient
+    pub fn build(&self) -> Gradient {
+        Gradient {
+            gradient: Box::new(HelixGradient {
+                start: self.start,
+                rotations: self.rotations,
+                hue: self.hue,
+                gamma: self.gamma,
+                lightness: self.lightness,
+                clamp_terms: self.clamp_terms,
+            }),
+            dmin: 0.0,
+            dmax: 1.0,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{to_laba, to_oklaba, Color};
+
+/// Perceptual color difference metric
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum DeltaE {
+    /// Euclidean distance in Oklab. Black to white is 1.0.
+    Oklab,
+    /// CIEDE2000 in CIE L\*a\*b\* (D65). Black to white is 100.0.
+    Ciede2000,
+}
+
+impl DeltaE {
+    /// Get the difference between two colors, the alpha channel is ignored.
+    ///
+    /// ```
+    /// use colorgrad::{Color, DeltaE};
+    ///
+    /// let black = Color::new(0.0, 0.0, 0.0, 1.0);
+    /// let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    ///
+    /// assert!((DeltaE::Oklab.distance(&black, &white) - 1.0).abs() < 1e-3);
+    /// assert!((DeltaE::Ciede2000.distance(&black, &white) - 100.0).abs() < 1e-3);
+    /// ```
+    pub fn distance(&self, a: &Color, b: &Color) -> f64 {
+        match self {
+            Self::Oklab => {
+                let [l1, a1, b1, _] = to_oklaba(a);
+                let [l2, a2, b2, _] = to_oklaba(b);
+                ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+            }
+            Self::Ciede2000 => {
+                let [l1, a1, b1, _] = to_laba(a);
+                let [l2, a2, b2, _] = to_laba(b);
+                ciede2000([l1, a1, b1], [l2, a2, b2])
+            }
+        }
+    }
+
+    // Difference too small to be noticed
+    pub(crate) fn tolerance(&self) -> f64 {
+        match self {
+            Self::Oklab => 1e-3,
+            Self::Ciede2000 => 0.1,
+        }
+    }
+}
+
+// The CIEDE2000 color-difference formula
+// http://www2.ece.rochester.edu/~gsharma/ciede2000/ciede2000noteCRNA.pdf
+
+fn ciede2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
+    let [l1, a1, b1] = lab1;
+    let [l2, a2, b2] = lab2;
+
+    let pow25_7 = 25f64.powi(7);
+    let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt());
+
+    let a1p = (1.0 + g) * a1;
+    let a2p = (1.0 + g) * a2;
+    let c1p = a1p.hypot(b1);
+    let c2p = a2p.hypot(b2);
+
+    let hue = |b: f64, a: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let h1p = hue(b1, a1p);
+    let h2p = hue(b2, a2p);
+
+    let delta_lp = l2 - l1;
+    let delta_cp = c2p - c1p;
+
+    let delta_hp = if c1p * c2p == 0.0 {
+        0.0
+    } else if (h2p - h1p).abs() <= 180.0 {
+        h2p - h1p
+    } else if h2p - h1p > 180.0 {
+        h2p - h1p - 360.0
+    } else {
+        h2p - h1p + 360.0
+    };
+    let delta_big_hp = 2.0 * (c1p * c2p).sqrt() * (delta_hp.to_radians() / 2.0).sin();
+
+    let l_bar_p = (l1 + l2) / 2.0;
+    let c_bar_p = (c1p + c2p) / 2.0;
+    let h_bar_p = if c1p * c2p == 0.0 {
+        h1p + h2p
+    } else if (h1p - h2p).abs() <= 180.0 {
+        (h1p + h2p) / 2.0
+    } else if h1p + h2p < 360.0 {
+        (h1p + h2p + 360.0) / 2.0
+    } else {
+        (h1p + h2p - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_bar_p - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar_p).to_radians().cos()
+        + 0.32 * (3.0 * h_bar_p + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar_p - 63.0).to_radians().cos();
+
+    let delta_theta = 30.0 * (-((h_bar_p - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar_p.powi(7) / (c_bar_p.powi(7) + pow25_7)).sqrt();
+    let l50 = (l_bar_p - 50.0).powi(2);
+    let s_l = 1.0 + 0.015 * l50 / (20.0 + l50).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar_p;
+    let s_h = 1.0 + 0.015 * c_bar_p * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let dl = delta_lp / s_l;
+    let dc = delta_cp / s_c;
+    let dh = delta_big_hp / s_h;
+
+    (dl * dl + dc * dc + dh * dh + r_t * dc * dh).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ciede2000() {
+        // Test data from Sharma, Wu, Dalal (2005)
+        let data = [
+            ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+            ([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+            ([50.0, -1.3802, -84.2814], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+            ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0011], 7.2195),
+            (
+                [60.2574, -34.0099, 36.2677],
+                [60.4626, -34.1751, 39.4387],
+                1.2644,
+            ),
+            (
+                [22.7233, 20.0904, -46.694],
+                [23.0331, 14.973, -42.5619],
+                2.0373,
+            ),
+            ([2.0776, 0.0795, -1.135], [0.9033, -0.0636, -0.5514], 0.9082),
+        ];
+
+        for (lab1, lab2, expected) in data {
+            let d = ciede2000(lab1, lab2);
+            assert!((d - expected).abs() < 1e-4, "{} != {}", d, expected);
+            let d = ciede2000(lab2, lab1);
+            assert!((d - expected).abs() < 1e-4, "{} != {}", d, expected);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{from_blend_space, BlendMode, DeltaE, Gradient};
+
+/// Rate of color change, see [`Gradient::derivative_at`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Derivative {
+    /// Color space of the channels. Hue is in degrees for [`BlendMode::Hsv`].
+    pub mode: BlendMode,
+    /// Derivative of each channel with respect to `t`
+    pub channels: [f64; 4],
+    /// Perceptual speed, Oklab ΔE per unit of `t`
+    pub speed: f64,
+}
+
+impl Derivative {
+    // The perceptual speed is the directional derivative of the Oklab color along
+    // the channel derivatives, estimated with a small step in the blend space.
+    pub(crate) fn new(mode: BlendMode, values: [f64; 4], channels: [f64; 4]) -> Self {
+        let max = channels.iter().fold(1.0, |m: f64, v| m.max(v.abs()));
+        let h = 1e-6 / max;
+        let mut v0 = values;
+        let mut v1 = values;
+        for i in 0..4 {
+            v0[i] -= h * channels[i];
+            v1[i] += h * channels[i];
+        }
+        let speed = DeltaE::Oklab
+            .distance(&from_blend_space(v0, mode), &from_blend_space(v1, mode))
+            / (2.0 * h);
+
+        Self {
+            mode,
+            channels,
+            speed,
+        }
+    }
+
+    // Central difference in sRGB, one-sided at the ends of the domain
+    pub(crate) fn numerical(gradient: &Gradient, t: f64) -> Self {
+        if t.is_nan() {
+            return Self {
+                mode: BlendMode::Rgb,
+                channels: [f64::NAN; 4],
+                speed: f64::NAN,
+            };
+        }
+
+        let (dmin, dmax) = gradient.domain();
+        let h = (dmax - dmin) * 1e-6;
+        let t0 = (t - h).max(dmin);
+        let t1 = (t + h).min(dmax);
+        let c0 = gradient.at(t0);
+        let c1 = gradient.at(t1);
+        let dt = t1 - t0;
+
+        Self {
+            mode: BlendMode::Rgb,
+            channels: [
+                (c1.r - c0.r) / dt,
+                (c1.g - c0.g) / dt,
+                (c1.b - c0.b) / dt,
+                (c1.a - c0.a) / dt,
+            ],
+            speed: DeltaE::Oklab.distance(&c0, &c1) / dt,
+        }
+    }
+
+    pub(crate) fn zero(self) -> Self {
+        Self {
+            mode: self.mode,
+            channels: [0.0; 4],
+            speed: 0.0,
+        }
+    }
+}
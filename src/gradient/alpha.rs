@@ -0,0 +1,42 @@
+use crate::{BlendMode, Color, GradientBase};
+
+// Gradient with the alpha channel taken from another gradient, so the opacity
+// can use its own interpolation and its own stops.
+
+pub(crate) struct AlphaGradient {
+    color: Box<dyn GradientBase + Send + Sync>,
+    alpha: Box<dyn GradientBase + Send + Sync>,
+}
+
+impl AlphaGradient {
+    pub(crate) fn new(
+        color: Box<dyn GradientBase + Send + Sync>,
+        alpha: Box<dyn GradientBase + Send + Sync>,
+    ) -> Self {
+        Self { color, alpha }
+    }
+}
+
+impl GradientBase for AlphaGradient {
+    fn at(&self, t: f64) -> Color {
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        let mut col = self.color.at(t);
+        col.a = self.alpha.at(t).a.clamp(0.0, 1.0);
+        col
+    }
+
+    fn derivative_at(&self, t: f64) -> Option<(BlendMode, [f64; 4], [f64; 4])> {
+        let (mode, mut values, mut derivs) = self.color.derivative_at(t)?;
+        let (_, alpha, alpha_derivs) = self.alpha.derivative_at(t)?;
+        values[3] = alpha[3];
+        derivs[3] = alpha_derivs[3];
+        Some((mode, values, derivs))
+    }
+
+    fn is_cyclic(&self) -> bool {
+        self.color.is_cyclic() && self.alpha.is_cyclic()
+    }
+}
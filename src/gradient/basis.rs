@@ -1,4 +1,4 @@
-use crate::{convert_colors, BlendMode, Color, GradientBase};
+use crate::{convert_colors, from_linear_rgba, from_oklaba, BlendMode, Color, GradientBase};
 
 // Basis spline algorithm adapted from:
 // https://github.com/d3/d3-interpolate/blob/master/src/basis.js
@@ -14,6 +14,17 @@ fn basis(t1: f64, v0: f64, v1: f64, v2: f64, v3: f64) -> f64 {
         / 6.0
 }
 
+// Derivative of basis() with respect to t1
+#[inline]
+fn basis_derivative(t1: f64, v0: f64, v1: f64, v2: f64, v3: f64) -> f64 {
+    let t2 = t1 * t1;
+    ((-3.0 + 6.0 * t1 - 3.0 * t2) * v0
+        + (-12.0 * t1 + 9.0 * t2) * v1
+        + (3.0 + 6.0 * t1 - 9.0 * t2) * v2
+        + 3.0 * t2 * v3)
+        / 6.0
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct BasisGradient {
     values: Vec<[f64; 4]>,
@@ -39,25 +50,11 @@ impl BasisGradient {
             last_color,
         }
     }
-}
-
-impl GradientBase for BasisGradient {
-    fn at(&self, t: f64) -> Color {
-        if t <= self.domain.0 {
-            return self.first_color.clone();
-        }
-
-        if t >= self.domain.1 {
-            return self.last_color.clone();
-        }
-
-        if t.is_nan() {
-            return Color::new(0.0, 0.0, 0.0, 1.0);
-        }
 
+    // Index of the first position at or after t, at least 1
+    fn segment(&self, t: f64) -> usize {
         let mut low = 0;
         let mut high = self.positions.len();
-        let n = high - 1;
 
         loop {
             if low >= high {
@@ -71,40 +68,85 @@ impl GradientBase for BasisGradient {
             }
         }
 
-        if low == 0 {
-            low = 1;
+        low.max(1)
+    }
+
+    // The four control values around segment i for channel j
+    fn control_values(&self, i: usize, j: usize) -> (f64, f64, f64, f64) {
+        let n = self.positions.len() - 1;
+        let v1 = self.values[i][j];
+        let v2 = self.values[i + 1][j];
+        let v0 = if i > 0 {
+            self.values[i - 1][j]
+        } else {
+            2.0 * v1 - v2
+        };
+        let v3 = if i < (n - 1) {
+            self.values[i + 2][j]
+        } else {
+            2.0 * v2 - v1
+        };
+        (v0, v1, v2, v3)
+    }
+}
+
+impl GradientBase for BasisGradient {
+    fn at(&self, t: f64) -> Color {
+        if t <= self.domain.0 {
+            return self.first_color.clone();
+        }
+
+        if t >= self.domain.1 {
+            return self.last_color.clone();
         }
 
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        let low = self.segment(t);
         let pos0 = self.positions[low - 1];
         let pos1 = self.positions[low];
-        let val0 = self.values[low - 1];
-        let val1 = self.values[low];
-        let i = low - 1;
         let t = (t - pos0) / (pos1 - pos0);
         let mut zz = [0.0; 4];
 
-        for (j, (v1, v2)) in val0.iter().zip(val1.iter()).enumerate() {
-            let v0 = if i > 0 {
-                self.values[i - 1][j]
-            } else {
-                2.0 * v1 - v2
-            };
-
-            let v3 = if i < (n - 1) {
-                self.values[i + 2][j]
-            } else {
-                2.0 * v2 - v1
-            };
-
-            zz[j] = basis(t, v0, *v1, *v2, v3);
+        for (j, z) in zz.iter_mut().enumerate() {
+            let (v0, v1, v2, v3) = self.control_values(low - 1, j);
+            *z = basis(t, v0, v1, v2, v3);
         }
 
         let [c0, c1, c2, c3] = zz;
 
         match self.mode {
-            BlendMode::LinearRgb => Color::from_linear_rgba(c0, c1, c2, c3),
-            BlendMode::Oklab => Color::from_oklaba(c0, c1, c2, c3),
+            BlendMode::LinearRgb => from_linear_rgba(c0, c1, c2, c3),
+            BlendMode::Oklab => from_oklaba(c0, c1, c2, c3),
             _ => Color::new(c0, c1, c2, c3),
         }
     }
+
+    fn derivative_at(&self, t: f64) -> Option<(BlendMode, [f64; 4], [f64; 4])> {
+        if t < self.domain.0 || t > self.domain.1 || t.is_nan() {
+            return None;
+        }
+
+        let low = self.segment(t);
+        let pos0 = self.positions[low - 1];
+        let pos1 = self.positions[low];
+        let h = pos1 - pos0;
+        let t = (t - pos0) / h;
+
+        let mut values = [0.0; 4];
+        let mut derivs = [0.0; 4];
+        for j in 0..4 {
+            let (v0, v1, v2, v3) = self.control_values(low - 1, j);
+            values[j] = basis(t, v0, v1, v2, v3);
+            derivs[j] = basis_derivative(t, v0, v1, v2, v3) / h;
+        }
+
+        Some((self.mode, values, derivs))
+    }
+
+    fn stops(&self) -> Vec<f64> {
+        self.positions.clone()
+    }
 }
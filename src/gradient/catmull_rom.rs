@@ -1,4 +1,4 @@
-use crate::{convert_colors, BlendMode, Color, GradientBase};
+use crate::{convert_colors, from_linear_rgba, from_oklaba, BlendMode, Color, GradientBase};
 
 // Catmull-Rom spline algorithm adapted from:
 // https://qroph.github.io/2018/07/30/smooth-paths-using-catmull-rom-splines.html
@@ -13,9 +13,7 @@ pub(crate) struct CatmullRomGradient {
     last_color: Color,
 }
 
-fn to_catmull_segments(values: &[f64]) -> Vec<[f64; 4]> {
-    let alpha = 0.5;
-    let tension = 0.0;
+fn to_catmull_segments(values: &[f64], alpha: f64, tension: f64) -> Vec<[f64; 4]> {
     let n = values.len();
 
     let mut vals = Vec::with_capacity(n + 2);
@@ -58,7 +56,13 @@ fn to_catmull_segments(values: &[f64]) -> Vec<[f64; 4]> {
 }
 
 impl CatmullRomGradient {
-    pub(crate) fn new(colors: Vec<Color>, positions: Vec<f64>, mode: BlendMode) -> Self {
+    pub(crate) fn new(
+        colors: Vec<Color>,
+        positions: Vec<f64>,
+        mode: BlendMode,
+        alpha: f64,
+        tension: f64,
+    ) -> Self {
         let n = colors.len();
         let mut a = Vec::with_capacity(n);
         let mut b = Vec::with_capacity(n);
@@ -72,10 +76,10 @@ impl CatmullRomGradient {
             d.push(col[3]);
         }
 
-        let s1 = to_catmull_segments(&a);
-        let s2 = to_catmull_segments(&b);
-        let s3 = to_catmull_segments(&c);
-        let s4 = to_catmull_segments(&d);
+        let s1 = to_catmull_segments(&a, alpha, tension);
+        let s2 = to_catmull_segments(&b, alpha, tension);
+        let s3 = to_catmull_segments(&c, alpha, tension);
+        let s4 = to_catmull_segments(&d, alpha, tension);
 
         let dmin = positions[0];
         let dmax = positions[positions.len() - 1];
@@ -97,22 +101,9 @@ impl CatmullRomGradient {
             last_color,
         }
     }
-}
-
-impl GradientBase for CatmullRomGradient {
-    fn at(&self, t: f64) -> Color {
-        if t <= self.domain.0 {
-            return self.first_color.clone();
-        }
-
-        if t >= self.domain.1 {
-            return self.last_color.clone();
-        }
-
-        if t.is_nan() {
-            return Color::new(0.0, 0.0, 0.0, 1.0);
-        }
 
+    // Index of the first position at or after t, at least 1
+    fn segment(&self, t: f64) -> usize {
         let mut low = 0;
         let mut high = self.positions.len();
 
@@ -128,10 +119,26 @@ impl GradientBase for CatmullRomGradient {
             }
         }
 
-        if low == 0 {
-            low = 1;
+        low.max(1)
+    }
+}
+
+impl GradientBase for CatmullRomGradient {
+    fn at(&self, t: f64) -> Color {
+        if t <= self.domain.0 {
+            return self.first_color.clone();
+        }
+
+        if t >= self.domain.1 {
+            return self.last_color.clone();
         }
 
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        let low = self.segment(t);
+
         let pos0 = self.positions[low - 1];
         let pos1 = self.positions[low];
         let [seg_a, seg_b, seg_c, seg_d] = self.segments[low - 1];
@@ -146,9 +153,36 @@ impl GradientBase for CatmullRomGradient {
         let c3 = seg_d[0] * t3 + seg_d[1] * t2 + seg_d[2] * t1 + seg_d[3];
 
         match self.mode {
-            BlendMode::LinearRgb => Color::from_linear_rgba(c0, c1, c2, c3),
-            BlendMode::Oklab => Color::from_oklaba(c0, c1, c2, c3),
+            BlendMode::LinearRgb => from_linear_rgba(c0, c1, c2, c3),
+            BlendMode::Oklab => from_oklaba(c0, c1, c2, c3),
             _ => Color::new(c0, c1, c2, c3),
         }
     }
+
+    fn derivative_at(&self, t: f64) -> Option<(BlendMode, [f64; 4], [f64; 4])> {
+        if t < self.domain.0 || t > self.domain.1 || t.is_nan() {
+            return None;
+        }
+
+        let low = self.segment(t);
+        let pos0 = self.positions[low - 1];
+        let pos1 = self.positions[low];
+        let h = pos1 - pos0;
+        let t1 = (t - pos0) / h;
+        let t2 = t1 * t1;
+        let t3 = t2 * t1;
+
+        let mut values = [0.0; 4];
+        let mut derivs = [0.0; 4];
+        for (i, [a, b, c, d]) in self.segments[low - 1].iter().enumerate() {
+            values[i] = a * t3 + b * t2 + c * t1 + d;
+            derivs[i] = (3.0 * a * t2 + 2.0 * b * t1 + c) / h;
+        }
+
+        Some((self.mode, values, derivs))
+    }
+
+    fn stops(&self) -> Vec<f64> {
+        self.positions.clone()
+    }
 }
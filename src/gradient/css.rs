@@ -0,0 +1,253 @@
+use crate::{from_laba, from_linear_rgba, from_oklaba, to_laba, to_linear_rgba, to_oklaba};
+use crate::{Color, ColorSpace, GradientBase, WideColor};
+
+// Color stops with the interpolation rules of CSS gradients (CSS Images 4, CSS Color 4):
+// colors are interpolated with premultiplied alpha in the chosen color space, polar
+// spaces use a hue interpolation method, and a color hint between two stops moves
+// their midpoint.
+// https://www.w3.org/TR/css-images-4/#coloring-gradient-line
+// https://www.w3.org/TR/css-color-4/#interpolation
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum CssSpace {
+    Srgb,
+    // Also XYZ: it is a linear transform of linear sRGB, so the result is the same
+    SrgbLinear,
+    DisplayP3,
+    Rec2020,
+    Lab,
+    Oklab,
+    Hsl,
+    Hwb,
+    Lch,
+    Oklch,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum HueMethod {
+    Shorter,
+    Longer,
+    Increasing,
+    Decreasing,
+}
+
+impl CssSpace {
+    // Index of the hue component, for polar color spaces
+    pub(crate) fn hue_index(self) -> Option<usize> {
+        match self {
+            Self::Hsl | Self::Hwb => Some(0),
+            Self::Lch | Self::Oklch => Some(2),
+            _ => None,
+        }
+    }
+
+    // Components and whether the hue is powerless (achromatic color)
+    fn convert(self, col: &Color) -> ([f64; 4], bool) {
+        match self {
+            Self::Srgb => ([col.r, col.g, col.b, col.a], false),
+            Self::SrgbLinear => (to_linear_rgba(col), false),
+            Self::DisplayP3 => (
+                WideColor::from_color(col, ColorSpace::DisplayP3).to_array(),
+                false,
+            ),
+            Self::Rec2020 => (
+                WideColor::from_color(col, ColorSpace::Rec2020).to_array(),
+                false,
+            ),
+            Self::Lab => (to_laba(col), false),
+            Self::Oklab => (to_oklaba(col), false),
+            Self::Hsl => {
+                let (h, s, l, a) = col.to_hsla();
+                ([h, s, l, a], s == 0.0)
+            }
+            Self::Hwb => {
+                let (h, w, b, a) = col.to_hwba();
+                ([h, w, b, a], w + b >= 1.0 - 1e-9)
+            }
+            Self::Lch | Self::Oklch => {
+                let [l, a, b, alpha] = if self == Self::Lch {
+                    to_laba(col)
+                } else {
+                    to_oklaba(col)
+                };
+                let c = a.hypot(b);
+                let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+                ([l, c, h, alpha], c < 1e-4)
+            }
+        }
+    }
+
+    fn to_color(self, v: [f64; 4]) -> Color {
+        let [c0, c1, c2, alpha] = v;
+        match self {
+            Self::Srgb => Color::new(c0, c1, c2, alpha),
+            Self::SrgbLinear => from_linear_rgba(c0, c1, c2, alpha),
+            Self::DisplayP3 => WideColor::new(ColorSpace::DisplayP3, c0, c1, c2, alpha).to_color(),
+            Self::Rec2020 => WideColor::new(ColorSpace::Rec2020, c0, c1, c2, alpha).to_color(),
+            Self::Lab => from_laba(c0, c1, c2, alpha),
+            Self::Oklab => from_oklaba(c0, c1, c2, alpha),
+            Self::Hsl => Color::from_hsla(c0, c1, c2, alpha),
+            Self::Hwb => Color::from_hwba(c0, c1, c2, alpha),
+            Self::Lch => {
+                let h = c2.to_radians();
+                from_laba(c0, c1 * h.cos(), c1 * h.sin(), alpha)
+            }
+            Self::Oklch => {
+                let h = c2.to_radians();
+                from_oklaba(c0, c1 * h.cos(), c1 * h.sin(), alpha)
+            }
+        }
+    }
+}
+
+// Adjust the hues of two colors so that linear interpolation between them follows
+// the hue interpolation method.
+fn fix_hues(h1: f64, h2: f64, method: HueMethod) -> (f64, f64) {
+    let d = h2 - h1;
+    match method {
+        HueMethod::Shorter if d > 180.0 => (h1 + 360.0, h2),
+        HueMethod::Shorter if d < -180.0 => (h1, h2 + 360.0),
+        HueMethod::Longer if 0.0 < d && d < 180.0 => (h1 + 360.0, h2),
+        HueMethod::Longer if -180.0 < d && d <= 0.0 => (h1, h2 + 360.0),
+        HueMethod::Increasing if d < 0.0 => (h1, h2 + 360.0),
+        HueMethod::Decreasing if d > 0.0 => (h1 + 360.0, h2),
+        _ => (h1, h2),
+    }
+}
+
+#[derive(Debug)]
+struct Segment {
+    // Premultiplied components of both ends
+    start: [f64; 4],
+    end: [f64; 4],
+    // Position of the color hint, relative to the segment
+    hint: Option<f64>,
+}
+
+#[derive(Debug)]
+pub(crate) struct CssStopsGradient {
+    positions: Vec<f64>,
+    segments: Vec<Segment>,
+    space: CssSpace,
+    first_color: Color,
+    last_color: Color,
+}
+
+impl CssStopsGradient {
+    // `hints` has one entry for every pair of adjacent stops, positions are sorted.
+    pub(crate) fn new(
+        colors: Vec<Color>,
+        positions: Vec<f64>,
+        hints: Vec<Option<f64>>,
+        space: CssSpace,
+        hue: HueMethod,
+    ) -> Self {
+        let hue_index = space.hue_index();
+        let values: Vec<([f64; 4], bool)> = colors.iter().map(|c| space.convert(c)).collect();
+
+        let premultiply = |mut v: [f64; 4]| {
+            let alpha = v[3];
+            for (i, c) in v.iter_mut().enumerate().take(3) {
+                if Some(i) != hue_index {
+                    *c *= alpha;
+                }
+            }
+            v
+        };
+
+        let segments = values
+            .windows(2)
+            .zip(positions.windows(2))
+            .zip(hints)
+            .map(|((v, p), hint)| {
+                let (mut start, powerless_0) = v[0];
+                let (mut end, powerless_1) = v[1];
+
+                if let Some(i) = hue_index {
+                    // A powerless hue takes the hue of the other color
+                    match (powerless_0, powerless_1) {
+                        (true, true) => {
+                            start[i] = 0.0;
+                            end[i] = 0.0;
+                        }
+                        (true, false) => start[i] = end[i],
+                        (false, true) => end[i] = start[i],
+                        (false, false) => {
+                            let (h1, h2) = fix_hues(start[i], end[i], hue);
+                            start[i] = h1;
+                            end[i] = h2;
+                        }
+                    }
+                }
+
+                let hint = match hint {
+                    Some(h) if p[1] > p[0] => Some(((h - p[0]) / (p[1] - p[0])).clamp(0.0, 1.0)),
+                    _ => None,
+                };
+
+                Segment {
+                    start: premultiply(start),
+                    end: premultiply(end),
+                    hint,
+                }
+            })
+            .collect();
+
+        Self {
+            first_color: colors[0].clone(),
+            last_color: colors[colors.len() - 1].clone(),
+            positions,
+            segments,
+            space,
+        }
+    }
+}
+
+impl GradientBase for CssStopsGradient {
+    fn at(&self, t: f64) -> Color {
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        if t < self.positions[0] {
+            return self.first_color.clone();
+        }
+
+        // At a hard stop (two stops at the same position) the later color wins
+        let i = self.positions.partition_point(|&p| p <= t);
+        if i >= self.positions.len() {
+            return self.last_color.clone();
+        }
+
+        let (p0, p1) = (self.positions[i - 1], self.positions[i]);
+        let seg = &self.segments[i - 1];
+        let mut x = (t - p0) / (p1 - p0);
+
+        if let Some(h) = seg.hint {
+            x = if h <= 0.0 {
+                1.0
+            } else if h >= 1.0 {
+                0.0
+            } else {
+                x.powf(0.5f64.ln() / h.ln())
+            };
+        }
+
+        let mut v = [0.0; 4];
+        for (i, c) in v.iter_mut().enumerate() {
+            *c = seg.start[i] + x * (seg.end[i] - seg.start[i]);
+        }
+
+        let hue_index = self.space.hue_index();
+        if v[3] > 0.0 {
+            let alpha = v[3];
+            for (i, c) in v.iter_mut().enumerate().take(3) {
+                if Some(i) != hue_index {
+                    *c /= alpha;
+                }
+            }
+        }
+
+        self.space.to_color(v)
+    }
+}
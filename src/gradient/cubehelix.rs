@@ -0,0 +1,56 @@
+use std::f64::consts::TAU;
+
+use crate::{Color, GradientBase};
+
+// Cubehelix color scheme as defined by D. A. Green (2011), "A colour scheme for
+// the display of astronomical intensity images", https://arxiv.org/abs/1108.5083
+//
+// Lightness runs from `lightness.0` to `lightness.1`, the color deviates from
+// gray along a helix around the gray diagonal of the RGB cube. The amplitude
+// runs from `hue.0` to `hue.1`, like D3's cubehelix interpolation. The hue angle
+// uses the lightness before gamma correction, the same as matplotlib and the
+// reference Fortran/IDL code.
+//
+// D3's cubehelix caps the red and green deviation terms at 1.0, `clamp_terms` does
+// the same so the cubehelix presets keep their colors.
+
+#[derive(Debug, Clone)]
+pub(crate) struct HelixGradient {
+    pub(crate) start: f64,
+    pub(crate) rotations: f64,
+    pub(crate) hue: (f64, f64),
+    pub(crate) gamma: f64,
+    pub(crate) lightness: (f64, f64),
+    pub(crate) clamp_terms: bool,
+}
+
+impl GradientBase for HelixGradient {
+    fn at(&self, t: f64) -> Color {
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        let x = t.clamp(0.0, 1.0);
+        let l = self.lightness.0 + x * (self.lightness.1 - self.lightness.0);
+
+        let angle = TAU * (self.start / 3.0 + 1.0 + self.rotations * l);
+        let l = l.powf(self.gamma);
+        let hue = self.hue.0 + x * (self.hue.1 - self.hue.0);
+        let amp = hue * l * (1.0 - l) / 2.0;
+
+        let (sin, cos) = angle.sin_cos();
+
+        let mut rt = 0.14861 * cos - 1.78277 * sin;
+        let mut gt = 0.29227 * cos + 0.90649 * sin;
+        if self.clamp_terms {
+            rt = rt.min(1.0);
+            gt = gt.min(1.0);
+        }
+
+        let r = l - amp * rt;
+        let g = l - amp * gt;
+        let b = l + amp * (1.97294 * cos);
+
+        Color::new(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0), 1.0)
+    }
+}
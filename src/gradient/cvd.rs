@@ -0,0 +1,32 @@
+use crate::{Color, Deficiency, GradientBase};
+
+pub(crate) struct CvdGradient {
+    gradient: Box<dyn GradientBase + Send + Sync>,
+    deficiency: Deficiency,
+    severity: f64,
+}
+
+impl CvdGradient {
+    pub(crate) fn new(
+        gradient: Box<dyn GradientBase + Send + Sync>,
+        deficiency: Deficiency,
+        severity: f64,
+    ) -> Self {
+        Self {
+            gradient,
+            deficiency,
+            severity,
+        }
+    }
+}
+
+impl GradientBase for CvdGradient {
+    fn at(&self, t: f64) -> Color {
+        self.deficiency
+            .simulate(&self.gradient.at(t), self.severity)
+    }
+
+    fn is_cyclic(&self) -> bool {
+        self.gradient.is_cyclic()
+    }
+}
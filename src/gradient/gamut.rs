@@ -0,0 +1,107 @@
+use crate::{from_oklaba, to_oklaba, Color, DeltaE, GamutMapping, GradientBase};
+
+// Gamut mapping algorithm from CSS Color Module Level 4
+// https://www.w3.org/TR/css-color-4/#binsearch
+
+// Tolerance for rounding errors of the color space conversions
+const EPSILON: f64 = 1e-5;
+
+// Just noticeable difference in Oklab
+const JND: f64 = 0.02;
+
+pub(crate) fn in_gamut(col: &Color) -> bool {
+    [col.r, col.g, col.b]
+        .iter()
+        .all(|v| (-EPSILON..=1.0 + EPSILON).contains(v))
+}
+
+fn clip(col: &Color) -> Color {
+    Color::new(
+        col.r.clamp(0.0, 1.0),
+        col.g.clamp(0.0, 1.0),
+        col.b.clamp(0.0, 1.0),
+        col.a,
+    )
+}
+
+pub(crate) fn map_color(col: Color, method: GamutMapping) -> Color {
+    match method {
+        GamutMapping::None => col,
+        GamutMapping::Clip => clip(&col),
+        GamutMapping::OklchChroma => oklch_chroma(col),
+    }
+}
+
+fn oklch_chroma(col: Color) -> Color {
+    if in_gamut(&col) {
+        return col;
+    }
+
+    let [l, a, b, alpha] = to_oklaba(&col);
+
+    if l >= 1.0 {
+        return Color::new(1.0, 1.0, 1.0, alpha);
+    }
+
+    if l <= 0.0 {
+        return Color::new(0.0, 0.0, 0.0, alpha);
+    }
+
+    let chroma = a.hypot(b);
+    let hue = b.atan2(a);
+
+    let mut clipped = clip(&col);
+    if DeltaE::Oklab.distance(&clipped, &col) < JND {
+        return clipped;
+    }
+
+    let mut min = 0.0;
+    let mut max = chroma;
+    let mut min_in_gamut = true;
+
+    while max - min > 1e-4 {
+        let c = (min + max) / 2.0;
+        let current = from_oklaba(l, c * hue.cos(), c * hue.sin(), alpha);
+
+        if min_in_gamut && in_gamut(&current) {
+            min = c;
+            continue;
+        }
+
+        clipped = clip(&current);
+        let e = DeltaE::Oklab.distance(&clipped, &current);
+
+        if e < JND {
+            if JND - e < 1e-4 {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = c;
+        } else {
+            max = c;
+        }
+    }
+
+    clipped
+}
+
+pub(crate) struct GamutMappedGradient {
+    gradient: Box<dyn GradientBase + Send + Sync>,
+    method: GamutMapping,
+}
+
+impl GamutMappedGradient {
+    pub(crate) fn new(gradient: Box<dyn GradientBase + Send + Sync>, method: GamutMapping) -> Self {
+        Self { gradient, method }
+    }
+}
+
+impl GradientBase for GamutMappedGradient {
+    fn at(&self, t: f64) -> Color {
+        map_color(self.gradient.at(t), self.method)
+    }
+
+    fn is_cyclic(&self) -> bool {
+        self.gradient.is_cyclic()
+    }
+}
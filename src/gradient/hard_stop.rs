@@ -0,0 +1,84 @@
+use crate::{BlendMode, Color, GradientBase};
+
+// Gradient with one or more hard stops (two or more colors at the same
+// position). Every run of colors between the hard stops is an independent
+// gradient, so splines never cross the discontinuity. At the position of a
+// hard stop, the color on the right side is used.
+
+pub(crate) struct HardStopGradient {
+    runs: Vec<(f64, Box<dyn GradientBase + Send + Sync>)>,
+    domain: (f64, f64),
+    first_color: Color,
+    last_color: Color,
+}
+
+impl HardStopGradient {
+    pub(crate) fn new(
+        runs: Vec<(f64, Box<dyn GradientBase + Send + Sync>)>,
+        domain: (f64, f64),
+        first_color: Color,
+        last_color: Color,
+    ) -> Self {
+        Self {
+            runs,
+            domain,
+            first_color,
+            last_color,
+        }
+    }
+
+    // Index of the last run starting at or before t
+    fn run_index(&self, t: f64) -> usize {
+        let mut low = 0;
+        let mut high = self.runs.len();
+
+        loop {
+            if low >= high {
+                break;
+            }
+            let mid = (low + high) / 2;
+            if self.runs[mid].0 <= t {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        low.max(1) - 1
+    }
+
+    fn run(&self, t: f64) -> &(dyn GradientBase + Send + Sync) {
+        self.runs[self.run_index(t)].1.as_ref()
+    }
+}
+
+impl GradientBase for HardStopGradient {
+    fn at(&self, t: f64) -> Color {
+        if t < self.domain.0 {
+            return self.first_color.clone();
+        }
+
+        if t > self.domain.1 {
+            return self.last_color.clone();
+        }
+
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        self.run(t).at(t)
+    }
+
+    fn derivative_at(&self, t: f64) -> Option<(BlendMode, [f64; 4], [f64; 4])> {
+        if t < self.domain.0 || t > self.domain.1 || t.is_nan() {
+            return None;
+        }
+        let mut i = self.run_index(t);
+        // A hard stop at the end of the domain starts a run of zero length, use the
+        // run before it
+        if i > 0 && self.runs[i].0 >= self.domain.1 {
+            i -= 1;
+        }
+        self.runs[i].1.derivative_at(t)
+    }
+}
@@ -0,0 +1,291 @@
+use crate::{convert_colors, from_linear_rgba, from_oklaba, BlendMode, Color, GradientBase};
+
+// Cubic Hermite spline. Each channel is interpolated using a piecewise cubic
+// defined by the values and the tangents (dy/dx) at every position. The
+// tangents are computed by one of the functions below.
+
+#[derive(Debug, Clone)]
+pub(crate) struct HermiteGradient {
+    segments: Vec<[[f64; 4]; 4]>,
+    positions: Vec<f64>,
+    domain: (f64, f64),
+    mode: BlendMode,
+    first_color: Color,
+    last_color: Color,
+}
+
+fn to_hermite_segments(values: &[f64], positions: &[f64], tangents: &[f64]) -> Vec<[f64; 4]> {
+    let mut segments = Vec::with_capacity(values.len() - 1);
+
+    for i in 0..(values.len() - 1) {
+        let h = positions[i + 1] - positions[i];
+        let v1 = values[i];
+        let v2 = values[i + 1];
+        let m1 = tangents[i] * h;
+        let m2 = tangents[i + 1] * h;
+
+        let a = 2.0 * v1 - 2.0 * v2 + m1 + m2;
+        let b = -3.0 * v1 + 3.0 * v2 - 2.0 * m1 - m2;
+        let c = m1;
+        let d = v1;
+
+        segments.push([a, b, c, d]);
+    }
+    segments
+}
+
+fn secants(values: &[f64], positions: &[f64]) -> Vec<f64> {
+    values
+        .windows(2)
+        .zip(positions.windows(2))
+        .map(|(v, p)| (v[1] - v[0]) / (p[1] - p[0]))
+        .collect()
+}
+
+// Monotone cubic interpolation (Fritsch-Carlson, with the Fritsch-Butland
+// weighted harmonic mean for interior points), as used by PCHIP.
+// https://en.wikipedia.org/wiki/Monotone_cubic_interpolation
+
+pub(crate) fn monotone_tangents(values: &[f64], positions: &[f64]) -> Vec<f64> {
+    let n = values.len();
+    let delta = secants(values, positions);
+
+    if n == 2 {
+        return vec![delta[0], delta[0]];
+    }
+
+    let h: Vec<f64> = positions.windows(2).map(|p| p[1] - p[0]).collect();
+    let mut tangents = vec![0.0; n];
+
+    for i in 1..(n - 1) {
+        let (d0, d1) = (delta[i - 1], delta[i]);
+        if d0 * d1 <= 0.0 {
+            continue;
+        }
+        let w1 = 2.0 * h[i] + h[i - 1];
+        let w2 = h[i] + 2.0 * h[i - 1];
+        tangents[i] = (w1 + w2) / (w1 / d0 + w2 / d1);
+    }
+
+    tangents[0] = monotone_end_tangent(h[0], h[1], delta[0], delta[1]);
+    tangents[n - 1] = monotone_end_tangent(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+    tangents
+}
+
+// Shape-preserving three-point end condition
+fn monotone_end_tangent(h0: f64, h1: f64, d0: f64, d1: f64) -> f64 {
+    let m = ((2.0 * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+    if m.signum() != d0.signum() || d0 == 0.0 {
+        0.0
+    } else if d0.signum() != d1.signum() && m.abs() > (3.0 * d0).abs() {
+        3.0 * d0
+    } else {
+        m
+    }
+}
+
+// Akima spline
+// https://en.wikipedia.org/wiki/Akima_spline
+
+pub(crate) fn akima_tangents(values: &[f64], positions: &[f64]) -> Vec<f64> {
+    let n = values.len();
+    let delta = secants(values, positions);
+
+    if n == 2 {
+        return vec![delta[0], delta[0]];
+    }
+
+    // Secants extended with two extrapolated values on each side
+    let mut m = Vec::with_capacity(n + 3);
+    let d_1 = 2.0 * delta[0] - delta[1];
+    m.push(2.0 * d_1 - delta[0]);
+    m.push(d_1);
+    m.extend_from_slice(&delta);
+    let d_n = 2.0 * delta[n - 2] - delta[n - 3];
+    m.push(d_n);
+    m.push(2.0 * d_n - delta[n - 2]);
+
+    (0..n)
+        .map(|i| {
+            // m[i + 2] is the secant to the right of point i
+            let w1 = (m[i + 3] - m[i + 2]).abs();
+            let w2 = (m[i + 1] - m[i]).abs();
+            if w1 + w2 < f64::EPSILON {
+                (m[i + 1] + m[i + 2]) / 2.0
+            } else {
+                (w1 * m[i + 1] + w2 * m[i + 2]) / (w1 + w2)
+            }
+        })
+        .collect()
+}
+
+// Natural cubic spline (second derivative is zero at both ends)
+// https://en.wikipedia.org/wiki/Spline_interpolation
+
+pub(crate) fn natural_tangents(values: &[f64], positions: &[f64]) -> Vec<f64> {
+    let n = values.len();
+    let delta = secants(values, positions);
+
+    if n == 2 {
+        return vec![delta[0], delta[0]];
+    }
+
+    let h: Vec<f64> = positions.windows(2).map(|p| p[1] - p[0]).collect();
+
+    // Solve the tridiagonal system for the second derivatives of the interior
+    // points using the Thomas algorithm.
+    let mut c = vec![0.0; n];
+    let mut d = vec![0.0; n];
+
+    for i in 1..(n - 1) {
+        let a = h[i - 1];
+        let b = 2.0 * (h[i - 1] + h[i]);
+        let r = 6.0 * (delta[i] - delta[i - 1]);
+        let w = b - a * c[i - 1];
+        c[i] = h[i] / w;
+        d[i] = (r - a * d[i - 1]) / w;
+    }
+
+    let mut m2 = vec![0.0; n];
+    for i in (1..(n - 1)).rev() {
+        m2[i] = d[i] - c[i] * m2[i + 1];
+    }
+
+    let mut tangents: Vec<f64> = (0..(n - 1))
+        .map(|i| delta[i] - h[i] * (2.0 * m2[i] + m2[i + 1]) / 6.0)
+        .collect();
+    tangents.push(delta[n - 2] + h[n - 2] * (m2[n - 2] + 2.0 * m2[n - 1]) / 6.0);
+    tangents
+}
+
+impl HermiteGradient {
+    pub(crate) fn new(
+        colors: Vec<Color>,
+        positions: Vec<f64>,
+        mode: BlendMode,
+        tangents: fn(&[f64], &[f64]) -> Vec<f64>,
+    ) -> Self {
+        let n = colors.len();
+        let mut channels = [
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        ];
+
+        for col in convert_colors(&colors, mode) {
+            for (ch, v) in channels.iter_mut().zip(col.iter()) {
+                ch.push(*v);
+            }
+        }
+
+        let [s1, s2, s3, s4] = channels.map(|values| {
+            let m = tangents(&values, &positions);
+            to_hermite_segments(&values, &positions, &m)
+        });
+
+        let dmin = positions[0];
+        let dmax = positions[positions.len() - 1];
+        let first_color = colors[0].clone();
+        let last_color = colors[colors.len() - 1].clone();
+
+        Self {
+            segments: s1
+                .iter()
+                .zip(&s2)
+                .zip(&s3)
+                .zip(&s4)
+                .map(|(((a, b), c), d)| [*a, *b, *c, *d])
+                .collect(),
+            positions,
+            domain: (dmin, dmax),
+            mode,
+            first_color,
+            last_color,
+        }
+    }
+
+    // Index of the first position at or after t, at least 1
+    fn segment(&self, t: f64) -> usize {
+        let mut low = 0;
+        let mut high = self.positions.len();
+
+        loop {
+            if low >= high {
+                break;
+            }
+            let mid = (low + high) / 2;
+            if self.positions[mid] < t {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        low.max(1)
+    }
+}
+
+impl GradientBase for HermiteGradient {
+    fn at(&self, t: f64) -> Color {
+        if t <= self.domain.0 {
+            return self.first_color.clone();
+        }
+
+        if t >= self.domain.1 {
+            return self.last_color.clone();
+        }
+
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        let low = self.segment(t);
+
+        let pos0 = self.positions[low - 1];
+        let pos1 = self.positions[low];
+        let [seg_a, seg_b, seg_c, seg_d] = self.segments[low - 1];
+
+        let t1 = (t - pos0) / (pos1 - pos0);
+        let t2 = t1 * t1;
+        let t3 = t2 * t1;
+
+        let c0 = seg_a[0] * t3 + seg_a[1] * t2 + seg_a[2] * t1 + seg_a[3];
+        let c1 = seg_b[0] * t3 + seg_b[1] * t2 + seg_b[2] * t1 + seg_b[3];
+        let c2 = seg_c[0] * t3 + seg_c[1] * t2 + seg_c[2] * t1 + seg_c[3];
+        let c3 = seg_d[0] * t3 + seg_d[1] * t2 + seg_d[2] * t1 + seg_d[3];
+
+        match self.mode {
+            BlendMode::LinearRgb => from_linear_rgba(c0, c1, c2, c3),
+            BlendMode::Oklab => from_oklaba(c0, c1, c2, c3),
+            _ => Color::new(c0, c1, c2, c3),
+        }
+    }
+
+    fn derivative_at(&self, t: f64) -> Option<(BlendMode, [f64; 4], [f64; 4])> {
+        if t < self.domain.0 || t > self.domain.1 || t.is_nan() {
+            return None;
+        }
+
+        let low = self.segment(t);
+        let pos0 = self.positions[low - 1];
+        let pos1 = self.positions[low];
+        let h = pos1 - pos0;
+        let t1 = (t - pos0) / h;
+        let t2 = t1 * t1;
+        let t3 = t2 * t1;
+
+        let mut values = [0.0; 4];
+        let mut derivs = [0.0; 4];
+        for (i, [a, b, c, d]) in self.segments[low - 1].iter().enumerate() {
+            values[i] = a * t3 + b * t2 + c * t1 + d;
+            derivs[i] = (3.0 * a * t2 + 2.0 * b * t1 + c) / h;
+        }
+
+        Some((self.mode, values, derivs))
+    }
+
+    fn stops(&self) -> Vec<f64> {
+        self.positions.clone()
+    }
+}
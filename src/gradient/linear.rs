@@ -1,4 +1,7 @@
-use crate::{convert_colors, interp_angle, linear_interpolation, BlendMode, Color, GradientBase};
+use crate::{
+    convert_colors, from_linear_rgba, from_oklaba, interp_angle, linear_interpolation, BlendMode,
+    Color, GradientBase,
+};
 
 #[derive(Debug)]
 pub(crate) struct LinearGradient {
@@ -30,22 +33,9 @@ impl LinearGradient {
             last_color,
         }
     }
-}
-
-impl GradientBase for LinearGradient {
-    fn at(&self, t: f64) -> Color {
-        if t <= self.dmin {
-            return self.first_color.clone();
-        }
-
-        if t >= self.dmax {
-            return self.last_color.clone();
-        }
-
-        if t.is_nan() {
-            return Color::new(0.0, 0.0, 0.0, 1.0);
-        }
 
+    // Index of the first stop at or after t, at least 1
+    fn segment(&self, t: f64) -> usize {
         let mut low = 0;
         let mut high = self.stops.len();
 
@@ -61,10 +51,26 @@ impl GradientBase for LinearGradient {
             }
         }
 
-        if low == 0 {
-            low = 1;
+        low.max(1)
+    }
+}
+
+impl GradientBase for LinearGradient {
+    fn at(&self, t: f64) -> Color {
+        if t <= self.dmin {
+            return self.first_color.clone();
+        }
+
+        if t >= self.dmax {
+            return self.last_color.clone();
+        }
+
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
         }
 
+        let low = self.segment(t);
+
         let (pos_0, col_0) = self.stops[low - 1];
         let (pos_1, col_1) = self.stops[low];
         let t = (t - pos_0) / (pos_1 - pos_0);
@@ -72,12 +78,50 @@ impl GradientBase for LinearGradient {
 
         match self.mode {
             BlendMode::Rgb => Color::new(a, b, c, d),
-            BlendMode::LinearRgb => Color::from_linear_rgba(a, b, c, d),
-            BlendMode::Oklab => Color::from_oklaba(a, b, c, d),
+            BlendMode::LinearRgb => from_linear_rgba(a, b, c, d),
+            BlendMode::Oklab => from_oklaba(a, b, c, d),
             BlendMode::Hsv => {
                 let hue = interp_angle(col_0[0], col_1[0], t);
                 Color::from_hsva(hue, b, c, d)
             }
         }
     }
+
+    fn derivative_at(&self, t: f64) -> Option<(BlendMode, [f64; 4], [f64; 4])> {
+        if t < self.dmin || t > self.dmax || t.is_nan() {
+            return None;
+        }
+
+        // Skip zero-length segments, use the last segment before them
+        let mut low = self.segment(t);
+        while low > 1 && self.stops[low].0 <= self.stops[low - 1].0 {
+            low -= 1;
+        }
+        let (pos_0, col_0) = self.stops[low - 1];
+        let (pos_1, col_1) = self.stops[low];
+        let h = pos_1 - pos_0;
+        if h <= 0.0 {
+            // A single color
+            return Some((self.mode, col_0, [0.0; 4]));
+        }
+        let t = (t - pos_0) / h;
+
+        let mut values = linear_interpolation(&col_0, &col_1, t);
+        let mut derivs = [0.0; 4];
+        for (d, (a, b)) in derivs.iter_mut().zip(col_0.iter().zip(&col_1)) {
+            *d = (b - a) / h;
+        }
+
+        if self.mode == BlendMode::Hsv {
+            values[0] = interp_angle(col_0[0], col_1[0], t);
+            // Shortest hue difference, as in interp_angle()
+            derivs[0] = ((((col_1[0] - col_0[0]) % 360.0) + 540.0) % 360.0 - 180.0) / h;
+        }
+
+        Some((self.mode, values, derivs))
+    }
+
+    fn stops(&self) -> Vec<f64> {
+        self.stops.iter().map(|s| s.0).collect()
+    }
 }
@@ -0,0 +1,28 @@
+use crate::{Color, GradientBase};
+
+// Lookup table with the same semantics as matplotlib's ListedColormap: the
+// domain is divided into `colors.len()` equal bins, t is mapped to the color of
+// the bin it falls in, and t = 1.0 to the last color.
+
+#[derive(Debug, Clone)]
+pub(crate) struct ListedGradient {
+    colors: Vec<Color>,
+}
+
+impl ListedGradient {
+    pub(crate) fn new(colors: Vec<Color>) -> Self {
+        Self { colors }
+    }
+}
+
+impl GradientBase for ListedGradient {
+    fn at(&self, t: f64) -> Color {
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        let n = self.colors.len();
+        let i = (t.clamp(0.0, 1.0) * n as f64) as usize;
+        self.colors[i.min(n - 1)].clone()
+    }
+}
@@ -1,6 +1,18 @@
+pub(crate) mod alpha;
 pub(crate) mod basis;
 pub(crate) mod catmull_rom;
+pub(crate) mod css;
+pub(crate) mod cubehelix;
+pub(crate) mod cvd;
+pub(crate) mod gamut;
 pub(crate) mod gimp;
+pub(crate) mod hard_stop;
+pub(crate) mod hermite;
 pub(crate) mod linear;
+pub(crate) mod listed;
 pub(crate) mod preset;
+pub(crate) mod reversed;
+pub(crate) mod segment;
 pub(crate) mod sharp;
+pub(crate) mod step;
+pub(crate) mod uniform;
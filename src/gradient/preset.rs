@@ -1,6 +1,9 @@
 use std::f64::consts::{FRAC_PI_3, PI};
 
-use crate::{linspace, BasisGradient, BlendMode, Color, Gradient, GradientBase};
+use crate::{
+    from_oklaba, linspace, BasisGradient, BlendMode, Color, CubehelixGradient, Gradient,
+    GradientBase, ListedGradient,
+};
 
 const PI2_3: f64 = PI * 2.0 / 3.0;
 
@@ -18,11 +21,14 @@ macro_rules! preset_fn {
 
 // Sinebow
 
-struct SinebowGradient {}
+struct SinebowGradient {
+    phase: f64,
+    cycles: f64,
+}
 
 impl GradientBase for SinebowGradient {
     fn at(&self, t: f64) -> Color {
-        let t = (0.5 - t) * PI;
+        let t = (0.5 - (self.phase + (self.cycles * t).rem_euclid(1.0)).rem_euclid(1.0)) * PI;
         Color::new(
             t.sin().powi(2).clamp(0.0, 1.0),
             (t + FRAC_PI_3).sin().powi(2).clamp(0.0, 1.0),
@@ -30,17 +36,58 @@ impl GradientBase for SinebowGradient {
             1.0,
         )
     }
+
+    fn is_cyclic(&self) -> bool {
+        self.cycles.fract() == 0.0
+    }
 }
 
-preset_fn!(sinebow; SinebowGradient{});
+preset_fn!(sinebow; SinebowGradient { phase: 0.0, cycles: 1.0 });
+
+/// Sinebow with a phase offset and a number of cycles.
+///
+/// The sinebow repeats every cycle, `phase` shifts it by a fraction of a cycle. The
+/// gradient is cyclic when `cycles` is a whole number. `sinebow_with(0.0, 1.0)` is
+/// the same as [`sinebow()`].
+///
+/// ```
+/// let g = colorgrad::sinebow_with(0.25, 2.0);
+/// assert_eq!(g.at(0.0).to_rgba8(), g.at(1.0).to_rgba8());
+/// assert_eq!(g.at(0.0).to_rgba8(), colorgrad::sinebow().at(0.25).to_rgba8());
+/// ```
+pub fn sinebow_with(phase: f64, cycles: f64) -> Gradient {
+    Gradient {
+        gradient: Box::new(SinebowGradient { phase, cycles }),
+        dmin: 0.0,
+        dmax: 1.0,
+    }
+}
+
+// Map t in [0..1] into [start..end] of a fixed colormap, there and back when mirrored
+fn sub_range(t: f64, start: f64, end: f64, mirrored: bool) -> f64 {
+    let t = t.clamp(0.0, 1.0);
+    let t = if mirrored {
+        1.0 - (2.0 * t - 1.0).abs()
+    } else {
+        t
+    };
+    (start + t * (end - start)).clamp(0.0, 1.0)
+}
 
 // Turbo
 
-struct TurboGradient {}
+struct TurboGradient {
+    start: f64,
+    end: f64,
+    mirrored: bool,
+}
 
 impl GradientBase for TurboGradient {
     fn at(&self, t: f64) -> Color {
-        let t = t.clamp(0.0, 1.0);
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+        let t = sub_range(t, self.start, self.end, self.mirrored);
         let r = (34.61
             + t * (1172.33 - t * (10793.56 - t * (33300.12 - t * (38394.49 - t * 14825.05)))))
             .round();
@@ -58,15 +105,44 @@ impl GradientBase for TurboGradient {
     }
 }
 
-preset_fn!(turbo; TurboGradient{});
+preset_fn!(turbo; TurboGradient {
+    start: 0.0,
+    end: 1.0,
+    mirrored: false,
+});
+
+/// Turbo restricted to the part between `start` and `end` (in [0..1]).
+///
+/// `start > end` reverses the colormap. When `mirrored` is `true` the gradient runs from
+/// `start` to `end` in the first half of the domain and back in the second half, so both
+/// ends have the same color. It isn't [cyclic](Gradient::is_cyclic): the direction
+/// reverses at the ends. `turbo_with(0.0, 1.0, false)` is the same as [`turbo()`].
+pub fn turbo_with(start: f64, end: f64, mirrored: bool) -> Gradient {
+    Gradient {
+        gradient: Box::new(TurboGradient {
+            start,
+            end,
+            mirrored,
+        }),
+        dmin: 0.0,
+        dmax: 1.0,
+    }
+}
 
 // Cividis
 
-struct CividisGradient {}
+struct CividisGradient {
+    start: f64,
+    end: f64,
+    mirrored: bool,
+}
 
 impl GradientBase for CividisGradient {
     fn at(&self, t: f64) -> Color {
-        let t = t.clamp(0.0, 1.0);
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+        let t = sub_range(t, self.start, self.end, self.mirrored);
         let r = (-4.54 - t * (35.34 - t * (2381.73 - t * (6402.7 - t * (7024.72 - t * 2710.57)))))
             .round();
         let g =
@@ -83,9 +159,31 @@ impl GradientBase for CividisGradient {
     }
 }
 
-preset_fn!(cividis; CividisGradient{});
+preset_fn!(cividis; CividisGradient {
+    start: 0.0,
+    end: 1.0,
+    mirrored: false,
+});
+
+/// Cividis restricted to the part between `start` and `end` (in [0..1]).
+///
+/// `start > end` reverses the colormap. When `mirrored` is `true` the gradient runs from
+/// `start` to `end` in the first half of the domain and back in the second half, so both
+/// ends have the same color. It isn't [cyclic](Gradient::is_cyclic): the direction
+/// reverses at the ends. `cividis_with(0.0, 1.0, false)` is the same as [`cividis()`].
+pub fn cividis_with(start: f64, end: f64, mirrored: bool) -> Gradient {
+    Gradient {
+        gradient: Box::new(CividisGradient {
+            start,
+            end,
+            mirrored,
+        }),
+        dmin: 0.0,
+        dmax: 1.0,
+    }
+}
 
-// Cubehelix
+// Cubehelix color, used by the rainbow gradient
 
 #[derive(Debug)]
 struct Cubehelix {
@@ -109,89 +207,170 @@ impl Cubehelix {
 
         Color::new(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0), 1.0)
     }
+}
 
-    fn interpolate(&self, other: &Cubehelix, t: f64) -> Cubehelix {
-        Cubehelix {
-            h: self.h + t * (other.h - self.h),
-            s: self.s + t * (other.s - self.s),
-            l: self.l + t * (other.l - self.l),
-        }
-    }
+// Cubehelix gradients of D3 (d3-scale-chromatic), with Green's parameters
+
+pub fn cubehelix_default() -> Gradient {
+    CubehelixGradient::new().clamp_terms().build()
+}
+
+pub fn warm() -> Gradient {
+    CubehelixGradient::new()
+        .start(2.0)
+        .rotations(10.0 / 9.0)
+        .hue_range(1.5, 3.0)
+        .lightness(0.35, 0.8)
+        .clamp_terms()
+        .build()
 }
 
-// Cubehelix gradient
+pub fn cool() -> Gradient {
+    CubehelixGradient::new()
+        .start(4.0 / 3.0)
+        .rotations(-10.0 / 9.0)
+        .hue_range(1.5, 3.0)
+        .lightness(0.35, 0.8)
+        .clamp_terms()
+        .build()
+}
 
-#[derive(Debug)]
-struct CubehelixGradient {
-    start: Cubehelix,
-    end: Cubehelix,
+// Rainbow
+
+struct RainbowGradient {
+    hue_offset: f64,
+    saturation: (f64, f64),
+    lightness: (f64, f64),
 }
 
-impl GradientBase for CubehelixGradient {
+impl GradientBase for RainbowGradient {
     fn at(&self, t: f64) -> Color {
-        self.start
-            .interpolate(&self.end, t.clamp(0.0, 1.0))
-            .to_color()
+        let t = t.clamp(0.0, 1.0);
+        let ts = 2.0 * (t - 0.5).abs();
+        let (s_end, s_mid) = self.saturation;
+        let (l_end, l_mid) = self.lightness;
+        Cubehelix {
+            h: 360.0 * t.rem_euclid(1.0) - 100.0 + self.hue_offset,
+            s: s_mid + (s_end - s_mid) * ts,
+            l: l_mid + (l_end - l_mid) * ts,
+        }
+        .to_color()
+    }
+
+    // The saturation and lightness curves turn at the ends, the hue alone is seamless
+    fn is_cyclic(&self) -> bool {
+        self.saturation.0 == self.saturation.1 && self.lightness.0 == self.lightness.1
     }
 }
 
-preset_fn!(cubehelix_default; CubehelixGradient {
-    start: Cubehelix {
-        h: 300.0,
-        s: 0.5,
-        l: 0.0,
-    },
-    end: Cubehelix {
-        h: -240.0,
-        s: 0.5,
-        l: 1.0,
-    },
+preset_fn!(rainbow; RainbowGradient {
+    hue_offset: 0.0,
+    saturation: (0.75, 1.5),
+    lightness: (0.35, 0.8),
 });
 
-preset_fn!(warm; CubehelixGradient {
-    start: Cubehelix {
-        h: -100.0,
-        s: 0.75,
-        l: 0.35,
-    },
-    end: Cubehelix {
-        h: 80.0,
-        s: 1.5,
-        l: 0.8,
-    },
-});
+/// Rainbow with a hue offset (in degrees) and custom saturation and lightness curves.
+///
+/// `saturation` and `lightness` are `(value at both ends, value in the middle)`, the
+/// curve is linear from the ends to the middle. Both ends always have the same color,
+/// animating `hue_offset` from 0 to 360 rotates the colors once. The gradient is only
+/// [cyclic](Gradient::is_cyclic) when both curves are flat, otherwise they change
+/// direction at the ends.
+/// `rainbow_with(0.0, (0.75, 1.5), (0.35, 0.8))` is the same as [`rainbow()`].
+///
+/// ```
+/// // Pastel rainbow
+/// let g = colorgrad::rainbow_with(0.0, (0.6, 0.9), (0.7, 0.85));
+/// assert_eq!(g.at(0.0).to_rgba8(), g.at(1.0).to_rgba8());
+/// ```
+pub fn rainbow_with(hue_offset: f64, saturation: (f64, f64), lightness: (f64, f64)) -> Gradient {
+    Gradient {
+        gradient: Box::new(RainbowGradient {
+            hue_offset,
+            saturation,
+            lightness,
+        }),
+        dmin: 0.0,
+        dmax: 1.0,
+    }
+}
 
-preset_fn!(cool; CubehelixGradient {
-    start: Cubehelix {
-        h: 260.0,
-        s: 0.75,
-        l: 0.35,
-    },
-    end: Cubehelix {
-        h: 80.0,
-        s: 1.5,
-        l: 0.8,
-    },
-});
+// Phase wheel
 
-// Rainbow
+// Circle of constant lightness and chroma in Oklab. At this lightness every
+// hue stays inside the sRGB gamut up to the chroma below, so the colors are
+// perceptually equidistant and the derivative is continuous across the seam.
 
-struct RainbowGradient {}
+const PHASE_WHEEL_L: f64 = 0.75;
+const PHASE_WHEEL_C: f64 = 0.125;
 
-impl GradientBase for RainbowGradient {
+struct PhaseWheelGradient {}
+
+impl GradientBase for PhaseWheelGradient {
     fn at(&self, t: f64) -> Color {
-        let t = t.clamp(0.0, 1.0);
-        let ts = (t - 0.5).abs();
-        Cubehelix {
-            h: 360.0 * t - 100.0,
-            s: 1.5 - 1.5 * ts,
-            l: 0.8 - 0.9 * ts,
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
         }
-        .to_color()
+        let h = 2.0 * PI * (t.clamp(0.0, 1.0).rem_euclid(1.0) + 0.08);
+        let c = from_oklaba(
+            PHASE_WHEEL_L,
+            PHASE_WHEEL_C * h.cos(),
+            PHASE_WHEEL_C * h.sin(),
+            1.0,
+        );
+        Color::new(
+            c.r.clamp(0.0, 1.0),
+            c.g.clamp(0.0, 1.0),
+            c.b.clamp(0.0, 1.0),
+            1.0,
+        )
+    }
+
+    fn is_cyclic(&self) -> bool {
+        true
     }
 }
 
-preset_fn!(rainbow; RainbowGradient{});
+preset_fn!(phase_wheel; PhaseWheelGradient{});
+
+// Lookup table
+
+/// Create gradient from a lookup table, like matplotlib's `ListedColormap`.
+///
+/// The domain [0..1] is divided into `colors.len()` equal bins and every position gets
+/// the color of its bin, without interpolation. Loading one of the published 256-entry
+/// tables (e.g. matplotlib's `_viridis_data`) reproduces that colormap exactly. The
+/// tables are not bundled: [`viridis()`], [`magma()`], [`inferno()`], [`plasma()`],
+/// [`turbo()`] and [`cividis()`] remain approximations.
+///
+/// ```
+/// use colorgrad::Color;
+///
+/// let g = colorgrad::listed(&[
+///     Color::from_rgba8(255, 0, 0, 255),
+///     Color::from_rgba8(0, 255, 0, 255),
+///     Color::from_rgba8(0, 0, 255, 255),
+/// ]);
+///
+/// assert_eq!(g.at(0.3).to_rgba8(), [255, 0, 0, 255]);
+/// assert_eq!(g.at(0.5).to_rgba8(), [0, 255, 0, 255]);
+/// assert_eq!(g.at(1.0).to_rgba8(), [0, 0, 255, 255]);
+/// ```
+pub fn listed(colors: &[Color]) -> Gradient {
+    let colors = if colors.is_empty() {
+        vec![
+            Color::new(0.0, 0.0, 0.0, 1.0),
+            Color::new(1.0, 1.0, 1.0, 1.0),
+        ]
+    } else {
+        colors.to_vec()
+    };
+    Gradient {
+        gradient: Box::new(ListedGradient::new(colors)),
+        dmin: 0.0,
+        dmax: 1.0,
+    }
+}
 
 // ---
 
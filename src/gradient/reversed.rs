@@ -0,0 +1,34 @@
+use crate::{Color, GradientBase};
+
+pub(crate) struct ReversedGradient {
+    gradient: Box<dyn GradientBase + Send + Sync>,
+    domain: (f64, f64),
+}
+
+impl ReversedGradient {
+    pub(crate) fn new(gradient: Box<dyn GradientBase + Send + Sync>, domain: (f64, f64)) -> Self {
+        Self { gradient, domain }
+    }
+}
+
+impl GradientBase for ReversedGradient {
+    fn at(&self, t: f64) -> Color {
+        self.gradient.at(self.domain.0 + self.domain.1 - t)
+    }
+
+    fn is_cyclic(&self) -> bool {
+        self.gradient.is_cyclic()
+    }
+
+    fn stops(&self) -> Vec<f64> {
+        let (dmin, dmax) = self.domain;
+        let mut stops: Vec<f64> = self
+            .gradient
+            .stops()
+            .iter()
+            .map(|t| dmin + dmax - t)
+            .collect();
+        stops.reverse();
+        stops
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{from_linear_rgba, from_oklaba, BlendMode, Color, GradientBase};
+
+// Per-channel piecewise linear gradient, evaluated the same way as matplotlib's
+// LinearSegmentedColormap. Every channel is a list of (x, y0, y1), the value
+// approaching x from the left is y0 and leaving x to the right is y1.
+// https://matplotlib.org/stable/api/_as_gen/matplotlib.colors.LinearSegmentedColormap.html
+
+#[derive(Debug, Clone)]
+pub(crate) struct SegmentGradient {
+    channels: [Vec<(f64, f64, f64)>; 4],
+    domain: (f64, f64),
+    mode: BlendMode,
+}
+
+impl SegmentGradient {
+    pub(crate) fn new(
+        channels: [Vec<(f64, f64, f64)>; 4],
+        domain: (f64, f64),
+        mode: BlendMode,
+    ) -> Self {
+        Self {
+            channels,
+            domain,
+            mode,
+        }
+    }
+}
+
+fn channel_at(data: &[(f64, f64, f64)], t: f64) -> f64 {
+    if t <= data[0].0 {
+        return data[0].2;
+    }
+
+    if t >= data[data.len() - 1].0 {
+        return data[data.len() - 1].1;
+    }
+
+    let mut low = 0;
+    let mut high = data.len();
+
+    loop {
+        if low >= high {
+            break;
+        }
+        let mid = (low + high) / 2;
+        if data[mid].0 < t {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    let (x0, _, y1) = data[low - 1];
+    let (x1, y0, _) = data[low];
+    y1 + (t - x0) / (x1 - x0) * (y0 - y1)
+}
+
+impl GradientBase for SegmentGradient {
+    fn at(&self, t: f64) -> Color {
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        // Map t into [0, 1]
+        let t = (t.clamp(self.domain.0, self.domain.1) - self.domain.0)
+            / (self.domain.1 - self.domain.0);
+
+        let [a, b, c, d] = [
+            channel_at(&self.channels[0], t),
+            channel_at(&self.channels[1], t),
+            channel_at(&self.channels[2], t),
+            channel_at(&self.channels[3], t),
+        ];
+
+        match self.mode {
+            BlendMode::Rgb => Color::new(a, b, c, d),
+            BlendMode::LinearRgb => from_linear_rgba(a, b, c, d),
+            BlendMode::Oklab => from_oklaba(a, b, c, d),
+            BlendMode::Hsv => Color::from_hsva(a, b, c, d),
+        }
+    }
+}
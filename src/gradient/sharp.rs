@@ -6,10 +6,12 @@ pub(crate) struct SharpGradient {
     domain: (f64, f64),
     first_color: Color,
     last_color: Color,
+    // Made from a cyclic gradient: the first and the last band have the same color
+    cyclic: bool,
 }
 
 impl SharpGradient {
-    pub(crate) fn new(colors_in: &[Color], domain: (f64, f64), t: f64) -> Self {
+    pub(crate) fn new(colors_in: &[Color], domain: (f64, f64), t: f64, cyclic: bool) -> Self {
         let n = colors_in.len();
         let mut colors = Vec::with_capacity(n * 2);
 
@@ -52,6 +54,7 @@ impl SharpGradient {
             domain,
             first_color,
             last_color,
+            cyclic,
         }
     }
 }
@@ -100,4 +103,8 @@ impl GradientBase for SharpGradient {
         let t = (t - pos_0) / (pos_1 - pos_0);
         col_0.interpolate_rgb(col_1, t)
     }
+
+    fn is_cyclic(&self) -> bool {
+        self.cyclic
+    }
 }
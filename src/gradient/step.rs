@@ -0,0 +1,84 @@
+use crate::{Color, GradientBase, StepPosition};
+
+#[derive(Debug, Clone)]
+pub(crate) struct StepGradient {
+    colors: Vec<Color>,
+    positions: Vec<f64>,
+    domain: (f64, f64),
+    step: StepPosition,
+}
+
+impl StepGradient {
+    pub(crate) fn new(colors: Vec<Color>, positions: Vec<f64>, step: StepPosition) -> Self {
+        let dmin = positions[0];
+        let dmax = positions[positions.len() - 1];
+        Self {
+            colors,
+            positions,
+            domain: (dmin, dmax),
+            step,
+        }
+    }
+}
+
+impl GradientBase for StepGradient {
+    fn at(&self, t: f64) -> Color {
+        if t <= self.domain.0 {
+            return self.colors[0].clone();
+        }
+
+        if t >= self.domain.1 {
+            return self.colors[self.colors.len() - 1].clone();
+        }
+
+        if t.is_nan() {
+            return Color::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        let mut low = 0;
+        let mut high = self.positions.len();
+
+        loop {
+            if low >= high {
+                break;
+            }
+            let mid = (low + high) / 2;
+            if self.positions[mid] < t {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 0 {
+            low = 1;
+        }
+
+        let pos0 = self.positions[low - 1];
+        let pos1 = self.positions[low];
+
+        let i = match self.step {
+            StepPosition::Left => {
+                if t < pos1 {
+                    low - 1
+                } else {
+                    low
+                }
+            }
+            StepPosition::Right => low,
+            StepPosition::Center => {
+                if t < (pos0 + pos1) / 2.0 {
+                    low - 1
+                } else {
+                    low
+                }
+            }
+        };
+
+        self.colors[i].clone()
+    }
+
+    fn stops(&self) -> Vec<f64> {
+        self.positions.clone()
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{linspace, Color, GradientBase};
+
+// Reparameterize a gradient by arc length, measured as the cumulative distance
+// (e.g. perceptual difference, or lightness difference) between closely spaced
+// samples. Intervals with a large step are subdivided, so steep parts (e.g. near
+// black) and hard edges are resolved finely.
+
+const SAMPLES: usize = 257;
+
+// Sample the gradient so that the distance between two consecutive samples is at
+// most `tolerance`, except across hard edges where intervals are
+// halved at most `max_depth` times. Returns the positions, the colors and the
+// difference to the previous sample.
+pub(crate) fn adaptive_samples<F, D>(
+    at: F,
+    domain: (f64, f64),
+    distance: D,
+    tolerance: f64,
+    max_depth: usize,
+) -> Vec<(f64, Color, f64)>
+where
+    F: Fn(f64) -> Color,
+    D: Fn(&Color, &Color) -> f64,
+{
+    let (dmin, dmax) = domain;
+    let mut samples = vec![(dmin, at(dmin), 0.0)];
+
+    for t in linspace(dmin, dmax, SAMPLES).into_iter().skip(1) {
+        // Stack of (position, color, depth) still to be added, nearest last
+        let mut stack = vec![(t, at(t), 0)];
+
+        while let Some((t1, c1, depth)) = stack.pop() {
+            let (t0, c0, _) = &samples[samples.len() - 1];
+            let d = distance(c0, &c1);
+
+            if d > tolerance && depth < max_depth {
+                let mid = (t0 + t1) / 2.0;
+                let cm = at(mid);
+                stack.push((t1, c1, depth + 1));
+                stack.push((mid, cm, depth + 1));
+                continue;
+            }
+
+            samples.push((t1, c1, d));
+        }
+    }
+
+    samples
+}
+
+pub(crate) struct UniformGradient {
+    gradient: Box<dyn GradientBase + Send + Sync>,
+    // Original positions and their arc length, scaled to the domain
+    positions: Vec<f64>,
+    distances: Vec<f64>,
+    domain: (f64, f64),
+}
+
+impl UniformGradient {
+    // Returns the gradient back if its length is below the tolerance, e.g. rounding
+    // noise of a constant lightness
+    pub(crate) fn new<D: Fn(&Color, &Color) -> f64>(
+        gradient: Box<dyn GradientBase + Send + Sync>,
+        domain: (f64, f64),
+        distance: D,
+        tolerance: f64,
+    ) -> Result<Self, Box<dyn GradientBase + Send + Sync>> {
+        let (dmin, dmax) = domain;
+
+        let samples = adaptive_samples(|t| gradient.at(t), domain, distance, tolerance, 24);
+
+        let mut positions = Vec::with_capacity(samples.len());
+        let mut distances = Vec::with_capacity(samples.len());
+        let mut total = 0.0;
+
+        for (t, _, d) in samples {
+            total += d;
+            positions.push(t);
+            distances.push(total);
+        }
+
+        if !(total > tolerance && total.is_finite()) {
+            return Err(gradient);
+        }
+
+        for d in distances.iter_mut() {
+            *d = dmin + *d / total * (dmax - dmin);
+        }
+
+        Ok(Self {
+            gradient,
+            positions,
+            distances,
+            domain,
+        })
+    }
+
+    // The new position of the color at position `t` of the original gradient
+    pub(crate) fn new_position(&self, t: f64) -> f64 {
+        if t <= self.domain.0 || t >= self.domain.1 || t.is_nan() {
+            return t;
+        }
+
+        let i = self.positions.partition_point(|&p| p < t).max(1);
+        let (p0, p1) = (self.positions[i - 1], self.positions[i]);
+        let (d0, d1) = (self.distances[i - 1], self.distances[i]);
+
+        d0 + (t - p0) / (p1 - p0) * (d1 - d0)
+    }
+}
+
+impl GradientBase for UniformGradient {
+    fn at(&self, t: f64) -> Color {
+        if t <= self.domain.0 || t >= self.domain.1 || t.is_nan() {
+            return self.gradient.at(t);
+        }
+
+        let mut low = 0;
+        let mut high = self.distances.len();
+
+        loop {
+            if low >= high {
+                break;
+            }
+            let mid = (low + high) / 2;
+            if self.distances[mid] < t {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 0 {
+            low = 1;
+        }
+
+        let d0 = self.distances[low - 1];
+        let d1 = self.distances[low];
+        let p0 = self.positions[low - 1];
+        let p1 = self.positions[low];
+
+        self.gradient.at(p0 + (t - d0) / (d1 - d0) * (p1 - p0))
+    }
+
+    fn is_cyclic(&self) -> bool {
+        self.gradient.is_cyclic()
+    }
+}
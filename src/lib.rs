@@ -124,13 +124,72 @@ pub use csscolorparser::{Color, ParseColorError};
 mod builder;
 pub use builder::{CustomGradient, CustomGradientError};
 
+pub mod brewer;
+
+pub mod css;
+pub use css::{parse as parse_css, ParseError as ParseCssError};
+
+mod cubehelix;
+pub use cubehelix::CubehelixGradient;
+
+mod palette;
+pub use palette::{Overflow, Palette};
+
+mod registry;
+pub use registry::{
+    preset_by_name, preset_info, presets, register_preset, PresetInfo, PresetKind,
+    RegisterPresetError,
+};
+
+mod spec;
+pub use spec::{parse_spec, ParseSpecError};
+
+mod segmented;
+pub use segmented::{SegmentedGradient, SegmentedGradientError};
+
+mod lookup;
+pub use lookup::PositionIndex;
+
+mod simplify;
+
+mod space;
+use space::{from_laba, from_linear_rgba, from_oklaba, to_laba, to_linear_rgba, to_oklaba};
+
+mod cvd;
+pub use cvd::{Deficiency, Separation};
+
+mod delta_e;
+pub use delta_e::DeltaE;
+
+mod derivative;
+pub use derivative::Derivative;
+
+mod analysis;
+pub use analysis::{Analysis, Jump, Monotonicity};
+
+mod lightness;
+pub use lightness::{LabSpace, LinearizedGradient, StopShift};
+pub use space::{ColorSpace, WideColor};
+
 mod gradient;
+use gradient::alpha::AlphaGradient;
 use gradient::basis::BasisGradient;
 use gradient::catmull_rom::CatmullRomGradient;
+use gradient::css::{CssSpace, CssStopsGradient, HueMethod};
+use gradient::cubehelix::HelixGradient;
+use gradient::cvd::CvdGradient;
+use gradient::gamut::{in_gamut, GamutMappedGradient};
 pub use gradient::gimp::{parse_ggr, ParseGgrError};
+use gradient::hard_stop::HardStopGradient;
+use gradient::hermite::{akima_tangents, monotone_tangents, natural_tangents, HermiteGradient};
 use gradient::linear::LinearGradient;
+use gradient::listed::ListedGradient;
 pub use gradient::preset::*;
+use gradient::reversed::ReversedGradient;
+use gradient::segment::SegmentGradient;
 use gradient::sharp::SharpGradient;
+use gradient::step::StepGradient;
+use gradient::uniform::UniformGradient;
 
 /// Color blending mode
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -147,10 +206,72 @@ pub enum Interpolation {
     Linear,
     Basis,
     CatmullRom,
+    /// Monotone cubic (Fritsch-Carlson), never overshoots between two stops
+    MonotoneCubic,
+    /// Akima spline, less wiggly than Catmull-Rom around outliers
+    Akima,
+    /// Natural cubic spline, smoothest curve through the stops but it may overshoot
+    NaturalCubic,
+    /// No blending, each color is held constant between the stops
+    Step(StepPosition),
+}
+
+/// Where the color changes in [`Interpolation::Step`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum StepPosition {
+    /// Each color is held from its position up to the next color's position
+    Left,
+    /// Each color is held from the previous color's position up to its position
+    Right,
+    /// The color changes midway between two positions
+    Center,
+}
+
+/// How colors are extended outside the domain, see [`Gradient::spread_at()`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Spread {
+    /// Use the color at the nearest end, as [`Gradient::at()`]
+    Pad,
+    /// Start again from the other end, as [`Gradient::repeat_at()`]
+    Repeat,
+    /// Go back and forth, as [`Gradient::reflect_at()`]
+    Reflect,
+}
+
+/// Gamut mapping method for colors outside the sRGB gamut
+///
+/// Blending in Oklab or with spline interpolation can produce colors outside the sRGB
+/// gamut. By default they are left as they are, and later clamped per channel by
+/// [`Color::to_rgba8()`], which can shift the hue.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum GamutMapping {
+    /// Leave the out-of-gamut colors as they are
+    None,
+    /// Clamp each channel into [0..1]
+    Clip,
+    /// Reduce the OkLCh chroma until the color is in gamut, keeping the lightness and hue
+    /// ([CSS Color Module Level 4](https://www.w3.org/TR/css-color-4/#binsearch))
+    OklchChroma,
 }
 
 trait GradientBase {
     fn at(&self, t: f64) -> Color;
+
+    // Analytic derivative: the blend mode, the channel values in that mode and their
+    // derivatives with respect to t. None if only the numerical derivative is available.
+    fn derivative_at(&self, _t: f64) -> Option<(BlendMode, [f64; 4], [f64; 4])> {
+        None
+    }
+
+    // Designed to wrap around: the colors at both ends of the domain are equal.
+    fn is_cyclic(&self) -> bool {
+        false
+    }
+
+    // Positions of the color stops, empty if the gradient isn't built from stops.
+    fn stops(&self) -> Vec<f64> {
+        Vec::new()
+    }
 }
 
 /// The gradient
@@ -171,10 +292,37 @@ impl fmt::Debug for Gradient {
 
 impl Gradient {
     /// Get color at certain position
+    ///
+    /// The color is in sRGB. It can be outside the sRGB gamut (channel values outside
+    /// [0..1]) if the gradient is made from wide-gamut colors, blended in Oklab or using
+    /// spline interpolation.
     pub fn at(&self, t: f64) -> Color {
         self.gradient.at(t)
     }
 
+    /// Get color at certain position, in the specified color space
+    ///
+    /// ```
+    /// use colorgrad::{ColorSpace, WideColor};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let g = colorgrad::CustomGradient::new()
+    ///     .wide_colors(&[
+    ///         WideColor::new(ColorSpace::DisplayP3, 1.0, 0.0, 0.0, 1.0),
+    ///         WideColor::new(ColorSpace::DisplayP3, 0.0, 0.0, 1.0, 1.0),
+    ///     ])
+    ///     .build()?;
+    ///
+    /// let c = g.at_in(0.0, ColorSpace::DisplayP3);
+    /// assert_eq!(c.space, ColorSpace::DisplayP3);
+    /// assert!((c.r - 1.0).abs() < 1e-9);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn at_in(&self, t: f64, space: ColorSpace) -> WideColor {
+        WideColor::from_color(&self.gradient.at(t), space)
+    }
+
     /// Get color at certain position
     pub fn repeat_at(&self, t: f64) -> Color {
         let t = norm(t, self.dmin, self.dmax);
@@ -189,6 +337,25 @@ impl Gradient {
             .at(self.dmin + (modulo(1.0 + t, 2.0) - 1.0).abs() * (self.dmax - self.dmin))
     }
 
+    /// Get color at certain position, extended outside the domain by `spread`
+    ///
+    /// ```
+    /// use colorgrad::Spread;
+    ///
+    /// let g = colorgrad::rd_bu();
+    ///
+    /// assert_eq!(g.spread_at(1.25, Spread::Pad).to_rgba8(), g.at(1.0).to_rgba8());
+    /// assert_eq!(g.spread_at(1.25, Spread::Repeat).to_rgba8(), g.at(0.25).to_rgba8());
+    /// assert_eq!(g.spread_at(1.25, Spread::Reflect).to_rgba8(), g.at(0.75).to_rgba8());
+    /// ```
+    pub fn spread_at(&self, t: f64, spread: Spread) -> Color {
+        match spread {
+            Spread::Pad => self.at(t),
+            Spread::Repeat => self.repeat_at(t),
+            Spread::Reflect => self.reflect_at(t),
+        }
+    }
+
     /// Get n colors evenly spaced across gradient
     pub fn colors(&self, n: usize) -> Vec<Color> {
         linspace(self.dmin, self.dmax, n)
@@ -197,11 +364,366 @@ impl Gradient {
             .collect()
     }
 
+    /// Get n colors evenly spaced across gradient, in the specified color space
+    pub fn colors_in(&self, n: usize, space: ColorSpace) -> Vec<WideColor> {
+        linspace(self.dmin, self.dmax, n)
+            .iter()
+            .map(|&t| WideColor::from_color(&self.gradient.at(t), space))
+            .collect()
+    }
+
     /// Get the gradient's domain min and max
     pub fn domain(&self) -> (f64, f64) {
         (self.dmin, self.dmax)
     }
 
+    /// Returns `true` if the gradient is cyclic: the colors at both ends of the domain
+    /// are equal and so is the rate of change, so [`repeat_at()`](Self::repeat_at)
+    /// wraps around without an edge.
+    ///
+    /// This is a property of how the gradient was made (e.g. [`sinebow()`],
+    /// [`phase_wheel()`]), not measured. Use
+    /// [`analyze()`](Self::analyze) to check any gradient.
+    ///
+    /// ```
+    /// assert!(colorgrad::sinebow().is_cyclic());
+    /// assert!(!colorgrad::viridis().is_cyclic());
+    /// ```
+    pub fn is_cyclic(&self) -> bool {
+        self.gradient.is_cyclic()
+    }
+
+    /// Returns `false` if the gradient produces any color outside the sRGB gamut, checked
+    /// at 1001 evenly spaced positions across the domain.
+    ///
+    /// ```
+    /// use colorgrad::{GamutMapping, Interpolation};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let g = colorgrad::CustomGradient::new()
+    ///     .html_colors(&["#000", "#fff", "#fff", "#000"])
+    ///     .interpolation(Interpolation::NaturalCubic)
+    ///     .build()?;
+    /// assert!(!g.in_gamut());
+    ///
+    /// let g = g.gamut_map(GamutMapping::OklchChroma);
+    /// assert!(g.in_gamut());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn in_gamut(&self) -> bool {
+        linspace(self.dmin, self.dmax, 1001)
+            .iter()
+            .all(|&t| in_gamut(&self.gradient.at(t)))
+    }
+
+    /// Get new gradient with the output colors mapped into the sRGB gamut
+    pub fn gamut_map(self, method: GamutMapping) -> Gradient {
+        if method == GamutMapping::None {
+            return self;
+        }
+        Gradient {
+            gradient: Box::new(GamutMappedGradient::new(self.gradient, method)),
+            dmin: self.dmin,
+            dmax: self.dmax,
+        }
+    }
+
+    /// Get new gradient with the same colors, reparameterized so that equal steps in `t`
+    /// give equal perceptual steps, measured with the `metric`.
+    ///
+    /// ```
+    /// use colorgrad::DeltaE;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let g = colorgrad::CustomGradient::new()
+    ///     .html_colors(&["#000", "#777", "#fff"])
+    ///     .domain(&[0.0, 0.1, 1.0])
+    ///     .build()?
+    ///     .uniformized(DeltaE::Oklab);
+    ///
+    /// let colors = g.colors(5);
+    /// let d1 = DeltaE::Oklab.distance(&colors[0], &colors[1]);
+    /// let d2 = DeltaE::Oklab.distance(&colors[3], &colors[4]);
+    /// assert!((d1 - d2).abs() < 1e-3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn uniformized(self, metric: DeltaE) -> Gradient {
+        match UniformGradient::new(
+            self.gradient,
+            (self.dmin, self.dmax),
+            |a, b| metric.distance(a, b),
+            metric.tolerance(),
+        ) {
+            Ok(gradbase) => Gradient {
+                gradient: Box::new(gradbase),
+                dmin: self.dmin,
+                dmax: self.dmax,
+            },
+            Err(gradient) => Gradient {
+                gradient,
+                dmin: self.dmin,
+                dmax: self.dmax,
+            },
+        }
+    }
+
+    /// Get new gradient whose lightness, measured in `space`, changes linearly across
+    /// the domain. Like colorcet, the colors are kept and only moved: `t` is
+    /// reparameterized by the cumulative lightness difference along the gradient.
+    ///
+    /// If the lightness increases or decreases monotonically, it becomes linear in `t`.
+    /// If it goes up and down (e.g. a diverging gradient), it changes at a constant rate
+    /// in every part. Parts with constant lightness are squeezed out, a gradient with
+    /// constant lightness is returned unchanged.
+    ///
+    /// The result reports where every color stop moved to. Gradients which aren't built
+    /// from color stops (e.g. most presets) report both ends of the domain.
+    ///
+    /// ```
+    /// use colorgrad::LabSpace;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let result = colorgrad::CustomGradient::new()
+    ///     .html_colors(&["#000", "#444", "#fff"])
+    ///     .mode(colorgrad::BlendMode::Oklab)
+    ///     .build()?
+    ///     .linearize_lightness(LabSpace::Oklab);
+    ///
+    /// let l = LabSpace::Oklab.lightness(&result.gradient.at(0.5));
+    /// assert!((l - 0.5).abs() < 1e-3);
+    /// assert_eq!(result.stops.len(), 3);
+    /// assert!(result.stops[1].new_position < 0.5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn linearize_lightness(self, space: LabSpace) -> LinearizedGradient {
+        let domain = (self.dmin, self.dmax);
+        let mut positions = self.gradient.stops();
+        if positions.is_empty() {
+            positions = vec![self.dmin, self.dmax];
+        }
+        let original: Vec<Color> = positions.iter().map(|&t| self.at(t)).collect();
+
+        let (gradient, new_positions) = match UniformGradient::new(
+            self.gradient,
+            domain,
+            |a, b| (space.lightness(a) - space.lightness(b)).abs(),
+            space.delta_e().tolerance(),
+        ) {
+            Ok(gradbase) => {
+                let new_positions = positions
+                    .iter()
+                    .map(|&t| gradbase.new_position(t))
+                    .collect();
+                (
+                    Box::new(gradbase) as Box<dyn GradientBase + Send + Sync>,
+                    new_positions,
+                )
+            }
+            Err(gradient) => (gradient, positions.clone()),
+        };
+
+        let gradient = Gradient {
+            gradient,
+            dmin: self.dmin,
+            dmax: self.dmax,
+        };
+        let metric = space.delta_e();
+
+        let stops = positions
+            .into_iter()
+            .zip(new_positions)
+            .zip(original)
+            .map(|((position, new_position), original)| {
+                let adjusted = gradient.at(position);
+                StopShift {
+                    position,
+                    new_position,
+                    lightness: space.lightness(&adjusted) - space.lightness(&original),
+                    delta_e: metric.distance(&original, &adjusted),
+                    original,
+                    adjusted,
+                }
+            })
+            .collect();
+
+        LinearizedGradient { gradient, stops }
+    }
+
+    /// Get colormap quality report, sampled at `n` evenly spaced positions
+    ///
+    /// ```
+    /// use colorgrad::{LabSpace, Monotonicity};
+    ///
+    /// let a = colorgrad::viridis().analyze(101, LabSpace::Cielab);
+    ///
+    /// assert_eq!(a.lightness_monotonicity, Monotonicity::Increasing);
+    /// assert!(a.in_gamut);
+    /// assert!(!a.cyclic);
+    ///
+    /// let a = colorgrad::sinebow().analyze(101, LabSpace::Oklab);
+    ///
+    /// assert_eq!(a.lightness_monotonicity, Monotonicity::None);
+    /// assert!(a.cyclic);
+    /// ```
+    pub fn analyze(&self, n: usize, space: LabSpace) -> Analysis {
+        Analysis::new(self, n, space)
+    }
+
+    /// Get new gradient simulating how this gradient looks for a viewer with the color
+    /// vision `deficiency`. `severity` in the range [0..1].
+    ///
+    /// ```
+    /// use colorgrad::Deficiency;
+    ///
+    /// let g = colorgrad::rd_yl_gn().simulate_cvd(Deficiency::Deutan, 1.0);
+    /// ```
+    pub fn simulate_cvd(self, deficiency: Deficiency, severity: f64) -> Gradient {
+        Gradient {
+            gradient: Box::new(CvdGradient::new(self.gradient, deficiency, severity)),
+            dmin: self.dmin,
+            dmax: self.dmax,
+        }
+    }
+
+    /// Measure how much perceptual separation (Oklab ΔE) between `n` evenly spaced
+    /// colors survives the color vision `deficiency`.
+    ///
+    /// ```
+    /// use colorgrad::Deficiency;
+    ///
+    /// let viridis = colorgrad::viridis().cvd_separation(Deficiency::Deutan, 1.0, 101);
+    /// let rd_yl_gn = colorgrad::rd_yl_gn().cvd_separation(Deficiency::Deutan, 1.0, 101);
+    ///
+    /// assert!(viridis.min_local_ratio > 0.8);
+    /// assert!(rd_yl_gn.min_local_ratio < 0.3);
+    /// ```
+    pub fn cvd_separation(&self, deficiency: Deficiency, severity: f64, n: usize) -> Separation {
+        Separation::new(self, deficiency, severity, n)
+    }
+
+    /// Get the rate of color change at `t`
+    ///
+    /// The channel derivatives are in the color space of the gradient's [`BlendMode`].
+    /// They are computed analytically for gradients built by [`CustomGradient`] with
+    /// linear, basis, Catmull-Rom and the other cubic interpolations. For other
+    /// gradients (presets, GIMP gradients, transformed gradients) the derivative is
+    /// estimated numerically in sRGB. Outside the domain the derivative is zero.
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let g = colorgrad::CustomGradient::new()
+    ///     .html_colors(&["#000", "#f00"])
+    ///     .domain(&[0.0, 10.0])
+    ///     .build()?;
+    ///
+    /// let d = g.derivative_at(5.0);
+    /// assert_eq!(d.channels, [0.1, 0.0, 0.0, 0.0]);
+    /// assert!(d.speed > 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn derivative_at(&self, t: f64) -> Derivative {
+        if t.is_nan() {
+            return Derivative::numerical(self, t);
+        }
+
+        let tc = t.clamp(self.dmin, self.dmax);
+        let d = match self.gradient.derivative_at(tc) {
+            Some((mode, values, channels)) => Derivative::new(mode, values, channels),
+            None => Derivative::numerical(self, tc),
+        };
+        if tc != t {
+            return d.zero();
+        }
+        d
+    }
+
+    /// Get the position of the color in the gradient, and the distance (measured with
+    /// `metric`) between the color and the gradient's color at that position. Returns
+    /// `None` if the distance is larger than `max_distance`.
+    ///
+    /// This builds a [`PositionIndex`] for each call, use [`Gradient::position_index`]
+    /// to look up many colors.
+    ///
+    /// ```
+    /// use colorgrad::{Color, DeltaE};
+    ///
+    /// let g = colorgrad::viridis();
+    ///
+    /// let (t, d) = g.position_of(&g.at(0.3), DeltaE::Oklab, None).unwrap();
+    /// assert!((t - 0.3).abs() < 1e-3);
+    /// assert!(d < 1e-3);
+    ///
+    /// let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(g.position_of(&red, DeltaE::Oklab, Some(0.05)), None);
+    /// ```
+    pub fn position_of(
+        &self,
+        col: &Color,
+        metric: DeltaE,
+        max_distance: Option<f64>,
+    ) -> Option<(f64, f64)> {
+        self.position_index(metric, max_distance).position_of(col)
+    }
+
+    /// Get precomputed index to find the position of colors in the gradient, see
+    /// [`Gradient::position_of`]
+    ///
+    /// ```
+    /// use colorgrad::DeltaE;
+    ///
+    /// let g = colorgrad::turbo();
+    /// let index = g.position_index(DeltaE::Oklab, Some(0.02));
+    ///
+    /// for t in [0.1, 0.25, 0.9] {
+    ///     let (pos, _) = index.position_of(&g.at(t)).unwrap();
+    ///     assert!((pos - t).abs() < 1e-2);
+    /// }
+    /// ```
+    pub fn position_index(&self, metric: DeltaE, max_distance: Option<f64>) -> PositionIndex<'_> {
+        PositionIndex::new(self, metric, max_distance)
+    }
+
+    /// Get a few linear color stops that reproduce this gradient, with every color
+    /// within `max_delta_e` (Oklab ΔE, and the same difference for alpha) when blended
+    /// linearly in `mode`. Works for any gradient, including presets and GIMP gradients.
+    ///
+    /// The stops are placed greedily, each as far as a quick search finds, so there are
+    /// few of them but not always the fewest possible.
+    ///
+    /// ```
+    /// use colorgrad::BlendMode;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let g = colorgrad::turbo().to_linear_stops(0.01, BlendMode::Oklab).build()?;
+    ///
+    /// assert_eq!(g.domain(), (0.0, 1.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_linear_stops(&self, max_delta_e: f64, mode: BlendMode) -> CustomGradient {
+        simplify::linear_stops(self, max_delta_e, mode)
+    }
+
+    /// Get new gradient with the colors in reverse order, in the same domain
+    ///
+    /// ```
+    /// let g = colorgrad::viridis().reversed();
+    ///
+    /// assert_eq!(g.at(0.0).to_hex_string(), "#fee825");
+    /// assert_eq!(g.at(1.0).to_hex_string(), "#440154");
+    /// ```
+    pub fn reversed(self) -> Gradient {
+        Gradient {
+            gradient: Box::new(ReversedGradient::new(self.gradient, (self.dmin, self.dmax))),
+            dmin: self.dmin,
+            dmax: self.dmax,
+        }
+    }
+
     /// Get new hard-edge gradient
     ///
     /// ```
@@ -219,7 +741,7 @@ impl Gradient {
         } else {
             vec![self.at(self.dmin), self.at(self.dmin)]
         };
-        let gradbase = SharpGradient::new(&colors, self.domain(), smoothness);
+        let gradbase = SharpGradient::new(&colors, self.domain(), smoothness, self.is_cyclic());
         Gradient {
             gradient: Box::new(gradbase),
             dmin: self.dmin,
@@ -233,17 +755,30 @@ impl Gradient {
 fn convert_colors(colors: &[Color], mode: BlendMode) -> Vec<[f64; 4]> {
     let mut result = Vec::with_capacity(colors.len());
     for col in colors.iter() {
-        let (a, b, c, d) = match mode {
-            BlendMode::Rgb => (col.r, col.g, col.b, col.a),
-            BlendMode::LinearRgb => col.to_linear_rgba(),
-            BlendMode::Oklab => col.to_oklaba(),
-            BlendMode::Hsv => col.to_hsva(),
+        let value = match mode {
+            BlendMode::Rgb => [col.r, col.g, col.b, col.a],
+            BlendMode::LinearRgb => to_linear_rgba(col),
+            BlendMode::Oklab => to_oklaba(col),
+            BlendMode::Hsv => {
+                let (h, s, v, a) = col.to_hsva();
+                [h, s, v, a]
+            }
         };
-        result.push([a, b, c, d]);
+        result.push(value);
     }
     result
 }
 
+// Inverse of convert_colors()
+fn from_blend_space(v: [f64; 4], mode: BlendMode) -> Color {
+    match mode {
+        BlendMode::Rgb => Color::new(v[0], v[1], v[2], v[3]),
+        BlendMode::LinearRgb => from_linear_rgba(v[0], v[1], v[2], v[3]),
+        BlendMode::Oklab => from_oklaba(v[0], v[1], v[2], v[3]),
+        BlendMode::Hsv => Color::from_hsva(v[0], v[1], v[2], v[3]),
+    }
+}
+
 fn linear_interpolation(a: &[f64; 4], b: &[f64; 4], t: f64) -> [f64; 4] {
     [
         a[0] + t * (b[0] - a[0]),
@@ -0,0 +1,73 @@
+use crate::{to_laba, to_oklaba, Color, DeltaE, Gradient};
+
+/// Color space used to measure lightness
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum LabSpace {
+    /// Oklab, lightness in the range [0..1]
+    Oklab,
+    /// CIE L\*a\*b\* (D65), lightness in the range [0..100]
+    Cielab,
+}
+
+impl LabSpace {
+    /// Get the lightness of the color
+    pub fn lightness(&self, col: &Color) -> f64 {
+        self.to_lab(col)[0]
+    }
+
+    /// The perceptual difference metric matching this color space
+    pub(crate) fn delta_e(&self) -> DeltaE {
+        match self {
+            Self::Oklab => DeltaE::Oklab,
+            Self::Cielab => DeltaE::Ciede2000,
+        }
+    }
+
+    /// Get the lightness, a, b and alpha of the color
+    pub fn to_lab(self, col: &Color) -> [f64; 4] {
+        match self {
+            Self::Oklab => to_oklaba(col),
+            Self::Cielab => to_laba(col),
+        }
+    }
+}
+
+/// How far one color stop moved
+#[derive(Debug, Clone, PartialEq)]
+pub struct StopShift {
+    /// Position of the stop in the original gradient
+    pub position: f64,
+    /// Position of the stop color in the new gradient
+    pub new_position: f64,
+    /// Color of the stop
+    pub original: Color,
+    /// Color of the new gradient at `position`
+    pub adjusted: Color,
+    /// Change of the lightness at `position`, `adjusted - original`
+    pub lightness: f64,
+    /// Perceptual difference between the original and the adjusted color, Oklab ΔE
+    /// for [`LabSpace::Oklab`], CIEDE2000 for [`LabSpace::Cielab`].
+    pub delta_e: f64,
+}
+
+/// The result of [`Gradient::linearize_lightness`]
+#[derive(Debug)]
+pub struct LinearizedGradient {
+    pub gradient: Gradient,
+    pub stops: Vec<StopShift>,
+}
+
+impl LinearizedGradient {
+    /// The largest distance a stop moved
+    pub fn max_shift(&self) -> f64 {
+        self.stops
+            .iter()
+            .map(|s| (s.new_position - s.position).abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// The largest perceptual difference of all stops
+    pub fn max_delta_e(&self) -> f64 {
+        self.stops.iter().map(|s| s.delta_e).fold(0.0, f64::max)
+    }
+}
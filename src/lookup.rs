@@ -0,0 +1,210 @@
+use crate::gradient::uniform::adaptive_samples;
+use crate::{to_oklaba, Color, DeltaE, Gradient};
+
+// Inverse lookup. The gradient is sampled densely, the samples are stored in a
+// k-d tree in Oklab for nearest neighbour search, and the position of the best
+// sample is refined by projecting onto the segments to its neighbours.
+
+// Largest Oklab difference between two consecutive samples
+const SAMPLE_DELTA: f64 = 0.002;
+
+struct KdTree {
+    points: Vec<[f64; 3]>,
+    // Point indices, each sub-slice has its node in the middle
+    nodes: Vec<usize>,
+}
+
+impl KdTree {
+    fn new(points: Vec<[f64; 3]>) -> Self {
+        let mut nodes: Vec<usize> = (0..points.len()).collect();
+        Self::build(&points, &mut nodes, 0);
+        Self { points, nodes }
+    }
+
+    fn build(points: &[[f64; 3]], nodes: &mut [usize], depth: usize) {
+        if nodes.len() < 2 {
+            return;
+        }
+        let axis = depth % 3;
+        nodes.sort_by(|a, b| points[*a][axis].total_cmp(&points[*b][axis]));
+        let mid = nodes.len() / 2;
+        let (left, right) = nodes.split_at_mut(mid);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    // Visit every point whose squared distance to q is at most the value
+    // returned by the previous visit (or the initial `radius2`).
+    fn search<F: FnMut(usize, f64) -> f64>(&self, q: [f64; 3], radius2: f64, visit: &mut F) {
+        self.search_in(&self.nodes, 0, q, radius2, visit);
+    }
+
+    fn search_in<F: FnMut(usize, f64) -> f64>(
+        &self,
+        nodes: &[usize],
+        depth: usize,
+        q: [f64; 3],
+        mut radius2: f64,
+        visit: &mut F,
+    ) -> f64 {
+        if nodes.is_empty() {
+            return radius2;
+        }
+
+        let mid = nodes.len() / 2;
+        let i = nodes[mid];
+        let p = self.points[i];
+        let d2 = (q[0] - p[0]).powi(2) + (q[1] - p[1]).powi(2) + (q[2] - p[2]).powi(2);
+        if d2 <= radius2 {
+            radius2 = visit(i, d2);
+        }
+
+        let axis = depth % 3;
+        let diff = q[axis] - p[axis];
+        let (near, far) = if diff < 0.0 {
+            (&nodes[..mid], &nodes[mid + 1..])
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid])
+        };
+
+        radius2 = self.search_in(near, depth + 1, q, radius2, visit);
+        if diff * diff <= radius2 {
+            radius2 = self.search_in(far, depth + 1, q, radius2, visit);
+        }
+        radius2
+    }
+}
+
+/// Precomputed index to find the position of colors in a gradient, see
+/// [`Gradient::position_index`]
+pub struct PositionIndex<'a> {
+    gradient: &'a Gradient,
+    metric: DeltaE,
+    max_distance: Option<f64>,
+    positions: Vec<f64>,
+    colors: Vec<Color>,
+    tree: KdTree,
+}
+
+impl<'a> PositionIndex<'a> {
+    pub(crate) fn new(gradient: &'a Gradient, metric: DeltaE, max_distance: Option<f64>) -> Self {
+        let samples = adaptive_samples(
+            |t| gradient.at(t),
+            gradient.domain(),
+            |a, b| DeltaE::Oklab.distance(a, b),
+            SAMPLE_DELTA,
+            8,
+        );
+
+        let mut positions = Vec::with_capacity(samples.len());
+        let mut colors = Vec::with_capacity(samples.len());
+        let mut points = Vec::with_capacity(samples.len());
+
+        for (t, col, _) in samples {
+            let [l, a, b, _] = to_oklaba(&col);
+            positions.push(t);
+            colors.push(col);
+            points.push([l, a, b]);
+        }
+
+        Self {
+            gradient,
+            metric,
+            max_distance,
+            positions,
+            colors,
+            tree: KdTree::new(points),
+        }
+    }
+
+    /// Get the position of the color in the gradient and its distance to the color at
+    /// that position. Returns `None` if the distance is larger than the maximum distance.
+    pub fn position_of(&self, col: &Color) -> Option<(f64, f64)> {
+        let [l, a, b, _] = to_oklaba(col);
+        let q = [l, a, b];
+        if q.iter().any(|v| v.is_nan()) {
+            return None;
+        }
+
+        // Nearest sample in Oklab. With a maximum Oklab distance the search can skip
+        // everything further away.
+        let radius = match (self.metric, self.max_distance) {
+            (DeltaE::Oklab, Some(max)) => max + SAMPLE_DELTA,
+            _ => f64::INFINITY,
+        };
+        let mut nearest = (usize::MAX, radius * radius);
+        self.tree.search(q, nearest.1, &mut |i, d2| {
+            if d2 <= nearest.1 {
+                nearest = (i, d2);
+            }
+            nearest.1
+        });
+
+        if nearest.0 == usize::MAX {
+            return None;
+        }
+
+        let mut best = nearest.0;
+
+        if self.metric != DeltaE::Oklab {
+            // The nearest sample in the other metric is among the samples close in Oklab
+            let radius = 2.0 * nearest.1.sqrt() + 0.02;
+            let mut best_d = f64::INFINITY;
+            self.tree.search(q, radius * radius, &mut |i, _| {
+                let d = self.metric.distance(col, &self.colors[i]);
+                if d < best_d {
+                    best_d = d;
+                    best = i;
+                }
+                radius * radius
+            });
+        }
+
+        let (t, d) = self.refine(col, q, best);
+
+        match self.max_distance {
+            Some(max) if d > max => None,
+            _ => Some((t, d)),
+        }
+    }
+
+    // Project the color onto the segments between sample i and its neighbours.
+    // The samples are close enough for the gradient to be almost straight there.
+    fn refine(&self, col: &Color, q: [f64; 3], i: usize) -> (f64, f64) {
+        let p = &self.tree.points;
+        let dist2 =
+            |x: [f64; 3]| (q[0] - x[0]).powi(2) + (q[1] - x[1]).powi(2) + (q[2] - x[2]).powi(2);
+        let mut best = (self.positions[i], dist2(p[i]));
+
+        for j in [i.wrapping_sub(1), i + 1] {
+            if j >= p.len() {
+                continue;
+            }
+            let v = [p[j][0] - p[i][0], p[j][1] - p[i][1], p[j][2] - p[i][2]];
+            let w = [q[0] - p[i][0], q[1] - p[i][1], q[2] - p[i][2]];
+            let len2 = v[0] * v[0] + v[1] * v[1] + v[2] * v[2];
+            if len2 == 0.0 {
+                continue;
+            }
+            let s = ((w[0] * v[0] + w[1] * v[1] + w[2] * v[2]) / len2).clamp(0.0, 1.0);
+            let x = [p[i][0] + s * v[0], p[i][1] + s * v[1], p[i][2] + s * v[2]];
+            let d2 = dist2(x);
+            if d2 < best.1 {
+                best = (
+                    self.positions[i] + s * (self.positions[j] - self.positions[i]),
+                    d2,
+                );
+            }
+        }
+
+        let t = best.0;
+        let d = self.metric.distance(col, &self.gradient.at(t));
+        let d_sample = self.metric.distance(col, &self.colors[i]);
+
+        if d <= d_sample {
+            (t, d)
+        } else {
+            (self.positions[i], d_sample)
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::ops::Index;
+
+use crate::{brewer, Color, Gradient, ListedGradient, ParseColorError};
+
+/// What happens when a [`Palette`] is indexed past its last color
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Overflow {
+    /// Start again from the first color
+    Wrap,
+    /// Keep using the last color
+    Clamp,
+}
+
+/// Discrete list of colors for categorical (qualitative) data
+///
+/// Unlike a [`Gradient`], the colors are not meant to be interpolated. A palette is
+/// indexed by integer; indices past the last color wrap around or are clamped.
+///
+/// # Examples
+///
+/// ```
+/// use colorgrad::{Overflow, Palette};
+///
+/// let p = Palette::set1();
+/// assert_eq!(p.len(), 9);
+/// assert_eq!(p[0].to_hex_string(), "#e41a1c");
+/// assert_eq!(p[9].to_hex_string(), "#e41a1c"); // wraps by default
+///
+/// let p = p.with_overflow(Overflow::Clamp);
+/// assert_eq!(p[9].to_hex_string(), "#999999");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    colors: Vec<Color>,
+    overflow: Overflow,
+}
+
+macro_rules! palette {
+    ($doc:literal; $colors:expr; $name:ident) => {
+        #[doc = $doc]
+        pub fn $name() -> Palette {
+            Palette::from_html($colors).unwrap()
+        }
+    };
+}
+
+// The largest class count of a ColorBrewer scheme
+macro_rules! brewer_palette {
+    ($doc:literal; $scheme:literal; $name:ident) => {
+        #[doc = $doc]
+        pub fn $name() -> Palette {
+            let n = *brewer::classes($scheme).unwrap().end();
+            brewer::scheme($scheme, n).unwrap().palette()
+        }
+    };
+}
+
+impl Palette {
+    /// Create palette from colors. Indices past the last color wrap around.
+    pub fn new(colors: &[Color]) -> Self {
+        Self {
+            colors: colors.to_vec(),
+            overflow: Overflow::Wrap,
+        }
+    }
+
+    /// Create palette from CSS colors
+    pub fn from_html<S: AsRef<str>>(html_colors: &[S]) -> Result<Self, ParseColorError> {
+        let colors = html_colors
+            .iter()
+            .map(|s| csscolorparser::parse(s.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(&colors))
+    }
+
+    /// Set what happens when the palette is indexed past its last color
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// All colors of the palette
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Get color at index `i`, wrapped or clamped. `None` if the palette is empty.
+    pub fn get(&self, i: usize) -> Option<&Color> {
+        if self.colors.is_empty() {
+            return None;
+        }
+        let i = match self.overflow {
+            Overflow::Wrap => i % self.colors.len(),
+            Overflow::Clamp => i.min(self.colors.len() - 1),
+        };
+        Some(&self.colors[i])
+    }
+
+    /// Get `n` colors, the first `n` of the palette. All colors are distinct when `n`
+    /// is at most [`len()`](Self::len), more than that are wrapped or clamped.
+    pub fn sample(&self, n: usize) -> Vec<Color> {
+        (0..n).filter_map(|i| self.get(i).cloned()).collect()
+    }
+
+    /// Convert into a gradient with domain [0..1] made of equal-width bands, one for
+    /// each color, without interpolation.
+    ///
+    /// ```
+    /// let g = colorgrad::Palette::okabe_ito().to_gradient();
+    ///
+    /// assert_eq!(g.at(0.0).to_hex_string(), "#000000");
+    /// assert_eq!(g.at(0.1).to_hex_string(), "#000000");
+    /// assert_eq!(g.at(0.2).to_hex_string(), "#e69f00");
+    /// assert_eq!(g.at(1.0).to_hex_string(), "#cc79a7");
+    /// ```
+    pub fn to_gradient(&self) -> Gradient {
+        let colors = if self.colors.is_empty() {
+            vec![Color::new(0.0, 0.0, 0.0, 1.0)]
+        } else {
+            self.colors.clone()
+        };
+        Gradient {
+            gradient: Box::new(ListedGradient::new(colors)),
+            dmin: 0.0,
+            dmax: 1.0,
+        }
+    }
+
+    // ColorBrewer qualitative schemes, with the most classes
+
+    brewer_palette!("ColorBrewer Accent, 8 colors"; "Accent"; accent);
+    brewer_palette!("ColorBrewer Dark2, 8 colors"; "Dark2"; dark2);
+    brewer_palette!("ColorBrewer Paired, 12 colors"; "Paired"; paired);
+    brewer_palette!("ColorBrewer Pastel1, 9 colors"; "Pastel1"; pastel1);
+    brewer_palette!("ColorBrewer Pastel2, 8 colors"; "Pastel2"; pastel2);
+    brewer_palette!("ColorBrewer Set1, 9 colors"; "Set1"; set1);
+    brewer_palette!("ColorBrewer Set2, 8 colors"; "Set2"; set2);
+    brewer_palette!("ColorBrewer Set3, 12 colors"; "Set3"; set3);
+
+    // Tableau
+
+    palette!("Tableau 10, 10 colors"; &["#4e79a7", "#f28e2c", "#e15759", "#76b7b2", "#59a14f", "#edc949", "#af7aa1", "#ff9da7", "#9c755f", "#bab0ab"]; tableau10);
+    palette!("Classic Tableau 20 (matplotlib's `tab20`), 20 colors, in light and dark pairs"; &["#1f77b4", "#aec7e8", "#ff7f0e", "#ffbb78", "#2ca02c", "#98df8a", "#d62728", "#ff9896", "#9467bd", "#c5b0d5", "#8c564b", "#c49c94", "#e377c2", "#f7b6d2", "#7f7f7f", "#c7c7c7", "#bcbd22", "#dbdb8d", "#17becf", "#9edae5"]; tableau20);
+
+    // D3 Category10, matplotlib's tab10
+
+    palette!("D3 Category10 (matplotlib's `tab10`), 10 colors"; &["#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f", "#bcbd22", "#17becf"]; category10);
+
+    // Okabe & Ito (2008), "Color Universal Design", safe for color vision deficiencies
+
+    palette!("Okabe & Ito, 8 colors, distinguishable with color vision deficiencies"; &["#000000", "#e69f00", "#56b4e9", "#009e73", "#f0e442", "#0072b2", "#d55e00", "#cc79a7"]; okabe_ito);
+}
+
+impl Index<usize> for Palette {
+    type Output = Color;
+
+    /// Color at index, wrapped or clamped
+    ///
+    /// # Panics
+    ///
+    /// Panics if the palette is empty.
+    fn index(&self, i: usize) -> &Color {
+        self.get(i).expect("empty palette")
+    }
+}
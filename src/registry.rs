@@ -0,0 +1,292 @@
+use std::sync::{Arc, RwLock};
+use std::{error, fmt};
+
+use crate::brewer::{self, Suitability};
+use crate::{Gradient, Palette};
+
+/// Kind of preset gradient
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PresetKind {
+    Sequential,
+    Diverging,
+    /// The colors at both ends are equal, so the gradient can be wrapped around without a
+    /// jump in color. That is all it means: [`Gradient::is_cyclic()`] also needs the rate of
+    /// change to match at the ends, which `rainbow` doesn't.
+    Cyclic,
+    /// Discrete colors, as equal-width bands without interpolation
+    Qualitative,
+}
+
+/// Description of a named gradient
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PresetInfo {
+    pub name: String,
+    pub kind: PresetKind,
+    /// Where the colors come from, e.g. `"ColorBrewer"` or `"matplotlib"`
+    pub source: String,
+    /// Still readable with color vision deficiencies
+    pub colorblind_safe: bool,
+}
+
+impl PresetInfo {
+    pub fn new(name: &str, kind: PresetKind) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            source: String::new(),
+            colorblind_safe: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum RegisterPresetError {
+    EmptyName,
+    /// A built-in preset has the same name
+    BuiltinName(String),
+}
+
+impl fmt::Display for RegisterPresetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::EmptyName => f.write_str("empty preset name"),
+            Self::BuiltinName(ref name) => write!(f, "'{}' is a built-in preset", name),
+        }
+    }
+}
+
+impl error::Error for RegisterPresetError {}
+
+struct Builtin {
+    name: &'static str,
+    gradient: fn() -> Gradient,
+    kind: PresetKind,
+    source: &'static str,
+    // None: ColorBrewer's rating of the scheme with the most classes
+    colorblind_safe: Option<bool>,
+}
+
+const BREWER: &str = "ColorBrewer";
+
+macro_rules! builtin {
+    ($name:ident, Palette, Brewer) => {
+        builtin!(@palette $name, BREWER, None)
+    };
+    ($name:ident, Palette, $source:expr, $safe:expr) => {
+        builtin!(@palette $name, $source, Some($safe))
+    };
+    ($name:ident, $kind:ident, Brewer) => {
+        builtin!(@gradient $name, $kind, BREWER, None)
+    };
+    ($name:ident, $kind:ident, $source:expr, $safe:expr) => {
+        builtin!(@gradient $name, $kind, $source, Some($safe))
+    };
+    (@palette $name:ident, $source:expr, $safe:expr) => {
+        Builtin {
+            name: stringify!($name),
+            gradient: || Palette::$name().to_gradient(),
+            kind: PresetKind::Qualitative,
+            source: $source,
+            colorblind_safe: $safe,
+        }
+    };
+    (@gradient $name:ident, $kind:ident, $source:expr, $safe:expr) => {
+        Builtin {
+            name: stringify!($name),
+            gradient: crate::$name,
+            kind: PresetKind::$kind,
+            source: $source,
+            colorblind_safe: $safe,
+        }
+    };
+}
+
+#[rustfmt::skip]
+const BUILTIN: &[Builtin] = &[
+    // Diverging
+    builtin!(br_bg, Diverging, Brewer),
+    builtin!(pr_gn, Diverging, Brewer),
+    builtin!(pi_yg, Diverging, Brewer),
+    builtin!(pu_or, Diverging, Brewer),
+    builtin!(rd_bu, Diverging, Brewer),
+    builtin!(rd_gy, Diverging, Brewer),
+    builtin!(rd_yl_bu, Diverging, Brewer),
+    builtin!(rd_yl_gn, Diverging, Brewer),
+    builtin!(spectral, Diverging, Brewer),
+    // Sequential (Single Hue)
+    builtin!(blues, Sequential, Brewer),
+    builtin!(greens, Sequential, Brewer),
+    builtin!(greys, Sequential, Brewer),
+    builtin!(oranges, Sequential, Brewer),
+    builtin!(purples, Sequential, Brewer),
+    builtin!(reds, Sequential, Brewer),
+    // Sequential (Multi-Hue)
+    builtin!(turbo, Sequential, "Google", false),
+    builtin!(viridis, Sequential, "matplotlib", true),
+    builtin!(inferno, Sequential, "matplotlib", true),
+    builtin!(magma, Sequential, "matplotlib", true),
+    builtin!(plasma, Sequential, "matplotlib", true),
+    builtin!(cividis, Sequential, "matplotlib", true),
+    builtin!(warm, Sequential, "D3", false),
+    builtin!(cool, Sequential, "D3", false),
+    builtin!(cubehelix_default, Sequential, "D3", true),
+    builtin!(bu_gn, Sequential, Brewer),
+    builtin!(bu_pu, Sequential, Brewer),
+    builtin!(gn_bu, Sequential, Brewer),
+    builtin!(or_rd, Sequential, Brewer),
+    builtin!(pu_bu_gn, Sequential, Brewer),
+    builtin!(pu_bu, Sequential, Brewer),
+    builtin!(pu_rd, Sequential, Brewer),
+    builtin!(rd_pu, Sequential, Brewer),
+    builtin!(yl_gn_bu, Sequential, Brewer),
+    builtin!(yl_gn, Sequential, Brewer),
+    builtin!(yl_or_br, Sequential, Brewer),
+    builtin!(yl_or_rd, Sequential, Brewer),
+    // Cyclic, equal colors at both ends (rainbow isn't seamless, see `is_cyclic()`)
+    builtin!(rainbow, Cyclic, "D3", false),
+    builtin!(sinebow, Cyclic, "D3", false),
+    builtin!(phase_wheel, Cyclic, "colorgrad", false),
+    // Qualitative
+    builtin!(accent, Palette, Brewer),
+    builtin!(dark2, Palette, Brewer),
+    builtin!(paired, Palette, Brewer),
+    builtin!(pastel1, Palette, Brewer),
+    builtin!(pastel2, Palette, Brewer),
+    builtin!(set1, Palette, Brewer),
+    builtin!(set2, Palette, Brewer),
+    builtin!(set3, Palette, Brewer),
+    builtin!(tableau10, Palette, "Tableau", false),
+    builtin!(tableau20, Palette, "Tableau", false),
+    builtin!(category10, Palette, "D3", false),
+    builtin!(okabe_ito, Palette, "Okabe & Ito", true),
+];
+
+type Constructor = Arc<dyn Fn() -> Gradient + Send + Sync>;
+
+static REGISTERED: RwLock<Vec<(PresetInfo, Constructor)>> = RwLock::new(Vec::new());
+
+// Names match ignoring case, '_', '-' and spaces: "rd_bu", "RdBu" and "rd-bu" are the same.
+pub(crate) fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn same_name(a: &str, b: &str) -> bool {
+    normalize(a) == normalize(b)
+}
+
+fn find_builtin(name: &str) -> Option<&'static Builtin> {
+    BUILTIN.iter().find(|b| same_name(b.name, name))
+}
+
+impl Builtin {
+    fn info(&self) -> PresetInfo {
+        let colorblind_safe = self.colorblind_safe.unwrap_or_else(|| {
+            let name = self.name.replace('_', "");
+            let n = *brewer::classes(&name).unwrap().end();
+            brewer::scheme(&name, n).unwrap().colorblind_safe == Suitability::Yes
+        });
+        PresetInfo {
+            name: self.name.to_string(),
+            kind: self.kind,
+            source: self.source.to_string(),
+            colorblind_safe,
+        }
+    }
+}
+
+/// Get preset gradient by name. Names are the preset function names (e.g. `"rd_bu"`,
+/// `"viridis"`), the qualitative [`Palette`] schemes (e.g. `"set1"`) and the gradients
+/// added with [`register_preset()`]. Case, `_`, `-` and spaces are ignored, so `"RdBu"`
+/// also works.
+///
+/// ```
+/// let g = colorgrad::preset_by_name("rd_bu").unwrap();
+/// assert_eq!(g.at(0.0).to_rgba8(), colorgrad::rd_bu().at(0.0).to_rgba8());
+///
+/// assert!(colorgrad::preset_by_name("RdBu").is_some());
+/// assert!(colorgrad::preset_by_name("foo").is_none());
+/// ```
+pub fn preset_by_name(name: &str) -> Option<Gradient> {
+    if let Some(b) = find_builtin(name) {
+        return Some((b.gradient)());
+    }
+    // Release the lock before calling the constructor, it may use the registry too
+    let constructor = {
+        let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+        registered
+            .iter()
+            .find(|(info, _)| same_name(&info.name, name))
+            .map(|(_, f)| Arc::clone(f))
+    };
+    constructor.map(|f| f())
+}
+
+/// Get the description of a preset by name
+pub fn preset_info(name: &str) -> Option<PresetInfo> {
+    if let Some(b) = find_builtin(name) {
+        return Some(b.info());
+    }
+    let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+    registered
+        .iter()
+        .find(|(info, _)| same_name(&info.name, name))
+        .map(|(info, _)| info.clone())
+}
+
+/// List all presets, the built-in ones first, then the registered ones in the order
+/// they were added.
+pub fn presets() -> Vec<PresetInfo> {
+    let mut list: Vec<PresetInfo> = BUILTIN.iter().map(|b| b.info()).collect();
+    let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+    list.extend(registered.iter().map(|(info, _)| info.clone()));
+    list
+}
+
+/// Add a named gradient to the registry, for [`preset_by_name()`] and [`presets()`].
+///
+/// Registering a name again replaces the gradient. Built-in names can't be replaced.
+///
+/// ```
+/// use colorgrad::{PresetInfo, PresetKind};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut info = PresetInfo::new("brand", PresetKind::Sequential);
+/// info.source = "ACME Corp.".to_string();
+///
+/// colorgrad::register_preset(info, || {
+///     colorgrad::CustomGradient::new()
+///         .html_colors(&["#fff", "#c00"])
+///         .build()
+///         .unwrap()
+/// })?;
+///
+/// let g = colorgrad::preset_by_name("brand").unwrap();
+/// assert_eq!(g.at(1.0).to_rgba8(), [204, 0, 0, 255]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_preset<F>(info: PresetInfo, gradient: F) -> Result<(), RegisterPresetError>
+where
+    F: Fn() -> Gradient + Send + Sync + 'static,
+{
+    if normalize(&info.name).is_empty() {
+        return Err(RegisterPresetError::EmptyName);
+    }
+    if let Some(b) = find_builtin(&info.name) {
+        return Err(RegisterPresetError::BuiltinName(b.name.to_string()));
+    }
+
+    let mut registered = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+    let entry = (info, Arc::new(gradient) as Constructor);
+    match registered
+        .iter()
+        .position(|(i, _)| same_name(&i.name, &entry.0.name))
+    {
+        Some(pos) => registered[pos] = entry,
+        None => registered.push(entry),
+    }
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use std::{error, fmt};
+
+use crate::{BlendMode, Gradient, SegmentGradient};
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum SegmentedGradientError {
+    MissingChannel,
+    WrongDomain,
+}
+
+impl fmt::Display for SegmentedGradientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::MissingChannel => f.write_str("missing channel"),
+            Self::WrongDomain => f.write_str("wrong domain"),
+        }
+    }
+}
+
+impl error::Error for SegmentedGradientError {}
+
+/// Create gradient from per-channel control points, like matplotlib's
+/// `LinearSegmentedColormap` segmentdata.
+///
+/// Each channel is a list of `(x, y0, y1)`. `x` must start at `0.0`, end at `1.0` and
+/// never decrease. Between two control points the channel is linearly interpolated from
+/// `y1` of the left point to `y0` of the right point, so `y0 != y1` makes a discontinuity.
+///
+/// The channels are in the space of the blend mode: red, green, blue for
+/// [`BlendMode::Rgb`] and [`BlendMode::LinearRgb`], L, a, b for [`BlendMode::Oklab`],
+/// hue (in degrees), saturation, value for [`BlendMode::Hsv`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // matplotlib's "hot" colormap
+/// let grad = colorgrad::SegmentedGradient::new()
+///     .red(&[(0.0, 0.0416, 0.0416), (0.365079, 1.0, 1.0), (1.0, 1.0, 1.0)])
+///     .green(&[(0.0, 0.0, 0.0), (0.365079, 0.0, 0.0), (0.746032, 1.0, 1.0), (1.0, 1.0, 1.0)])
+///     .blue(&[(0.0, 0.0, 0.0), (0.746032, 0.0, 0.0), (1.0, 1.0, 1.0)])
+///     .build()?;
+///
+/// assert_eq!(grad.at(0.0).to_rgba8(), [11, 0, 0, 255]);
+/// assert_eq!(grad.at(1.0).to_rgba8(), [255, 255, 255, 255]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SegmentedGradient {
+    channels: [Vec<(f64, f64, f64)>; 4],
+    domain: Vec<f64>,
+    mode: BlendMode,
+}
+
+impl SegmentedGradient {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            channels: [Vec::new(), Vec::new(), Vec::new(), Vec::new()],
+            domain: vec![0.0, 1.0],
+            mode: BlendMode::Rgb,
+        }
+    }
+
+    /// Set the control points of the first channel (red, L or hue)
+    pub fn red<'a>(&'a mut self, data: &[(f64, f64, f64)]) -> &'a mut Self {
+        self.channels[0] = data.to_vec();
+        self
+    }
+
+    /// Set the control points of the second channel (green, a or saturation)
+    pub fn green<'a>(&'a mut self, data: &[(f64, f64, f64)]) -> &'a mut Self {
+        self.channels[1] = data.to_vec();
+        self
+    }
+
+    /// Set the control points of the third channel (blue, b or value)
+    pub fn blue<'a>(&'a mut self, data: &[(f64, f64, f64)]) -> &'a mut Self {
+        self.channels[2] = data.to_vec();
+        self
+    }
+
+    /// Set the control points of the alpha channel. Default is fully opaque.
+    pub fn alpha<'a>(&'a mut self, data: &[(f64, f64, f64)]) -> &'a mut Self {
+        self.channels[3] = data.to_vec();
+        self
+    }
+
+    /// Set the gradient domain `[min, max]`, `x` in `[0, 1]` is mapped into it.
+    pub fn domain<'a>(&'a mut self, domain: &[f64]) -> &'a mut Self {
+        self.domain = domain.to_vec();
+        self
+    }
+
+    /// Set the color space of the channels
+    #[allow(clippy::needless_lifetimes)]
+    pub fn mode<'a>(&'a mut self, mode: BlendMode) -> &'a mut Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Build the gradient
+    pub fn build(&self) -> Result<Gradient, SegmentedGradientError> {
+        if self.domain.len() != 2 || self.domain[0] >= self.domain[1] {
+            return Err(SegmentedGradientError::WrongDomain);
+        }
+        let (dmin, dmax) = (self.domain[0], self.domain[1]);
+
+        let mut channels = self.channels.clone();
+
+        if channels[3].is_empty() {
+            channels[3] = vec![(0.0, 1.0, 1.0), (1.0, 1.0, 1.0)];
+        }
+
+        for data in channels.iter() {
+            if data.len() < 2 {
+                return Err(SegmentedGradientError::MissingChannel);
+            }
+            if data[0].0 != 0.0 || data[data.len() - 1].0 != 1.0 {
+                return Err(SegmentedGradientError::WrongDomain);
+            }
+            for p in data.windows(2) {
+                if p[0].0 > p[1].0 {
+                    return Err(SegmentedGradientError::WrongDomain);
+                }
+            }
+        }
+
+        Ok(Gradient {
+            gradient: Box::new(SegmentGradient::new(channels, (dmin, dmax), self.mode)),
+            dmin,
+            dmax,
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use crate::gradient::uniform::adaptive_samples;
+use crate::{
+    convert_colors, from_blend_space, interp_angle, linear_interpolation, BlendMode, Color,
+    CustomGradient, DeltaE, Gradient,
+};
+
+// Simplify a gradient into linear stops. The gradient is sampled densely, then
+// starting from the first sample every stop is placed greedily at a far sample
+// for which all the samples in between are within the tolerance. That sample is
+// found by doubling the segment length until it doesn't fit, then bisecting, so
+// the number of checks per stop is logarithmic. Whether a segment fits isn't
+// always monotone in its end, so this may stop before the furthest sample that
+// fits, and the result has few stops but not always the fewest.
+
+pub(crate) fn linear_stops(
+    gradient: &Gradient,
+    max_delta_e: f64,
+    mode: BlendMode,
+) -> CustomGradient {
+    let tolerance = if max_delta_e > 1e-4 {
+        max_delta_e
+    } else {
+        1e-4
+    };
+    let samples = adaptive_samples(
+        |t| gradient.at(t),
+        gradient.domain(),
+        |a, b| DeltaE::Oklab.distance(a, b),
+        tolerance / 2.0,
+        16,
+    );
+
+    let positions: Vec<f64> = samples.iter().map(|s| s.0).collect();
+    let colors: Vec<Color> = samples.into_iter().map(|s| s.1).collect();
+    let values = convert_colors(&colors, mode);
+
+    let fits = |i: usize, j: usize| {
+        (i + 1..j).all(|k| {
+            let t = (positions[k] - positions[i]) / (positions[j] - positions[i]);
+            let mut v = linear_interpolation(&values[i], &values[j], t);
+            if mode == BlendMode::Hsv {
+                v[0] = interp_angle(values[i][0], values[j][0], t);
+            }
+            let col = from_blend_space(v, mode);
+            DeltaE::Oklab.distance(&col, &colors[k]) <= tolerance
+                && (col.a - colors[k].a).abs() <= tolerance
+        })
+    };
+
+    let n = positions.len();
+    let mut stops = vec![0];
+    let mut i = 0;
+
+    while i < n - 1 {
+        // `good` fits, `bad` is past the end or doesn't fit
+        let mut good = i + 1;
+        let mut step = 1;
+        let mut bad = loop {
+            let j = good + step;
+            if j >= n {
+                break n;
+            }
+            if !fits(i, j) {
+                break j;
+            }
+            good = j;
+            step *= 2;
+        };
+
+        while bad - good > 1 {
+            let mid = (good + bad) / 2;
+            if fits(i, mid) {
+                good = mid;
+            } else {
+                bad = mid;
+            }
+        }
+
+        stops.push(good);
+        i = good;
+    }
+
+    let mut result = CustomGradient::new();
+    result
+        .colors(&stops.iter().map(|&i| colors[i].clone()).collect::<Vec<_>>())
+        .domain(&stops.iter().map(|&i| positions[i]).collect::<Vec<_>>())
+        .mode(mode);
+    result
+}
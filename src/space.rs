@@ -0,0 +1,309 @@
+use crate::Color;
+
+// Color space conversions. The matrices and transfer functions are taken from
+// CSS Color Module Level 4, https://www.w3.org/TR/css-color-4/#color-conversion-code
+//
+// Every `Color` in this crate is (extended-range) sRGB. Colors outside the sRGB
+// gamut have channel values outside [0..1], so the transfer functions are
+// extended to negative values by mirroring them around zero.
+
+type Matrix = [[f64; 3]; 3];
+
+const SRGB_TO_XYZ: Matrix = [
+    [506752.0 / 1228815.0, 87881.0 / 245763.0, 12673.0 / 70218.0],
+    [87098.0 / 409605.0, 175762.0 / 245763.0, 12673.0 / 175545.0],
+    [7918.0 / 409605.0, 87881.0 / 737289.0, 1001167.0 / 1053270.0],
+];
+
+const XYZ_TO_SRGB: Matrix = [
+    [12831.0 / 3959.0, -329.0 / 214.0, -1974.0 / 3959.0],
+    [
+        -851781.0 / 878810.0,
+        1648619.0 / 878810.0,
+        36519.0 / 878810.0,
+    ],
+    [705.0 / 12673.0, -2585.0 / 12673.0, 705.0 / 667.0],
+];
+
+const P3_TO_XYZ: Matrix = [
+    [
+        608311.0 / 1250200.0,
+        189793.0 / 714400.0,
+        198249.0 / 1000160.0,
+    ],
+    [
+        35783.0 / 156275.0,
+        247089.0 / 357200.0,
+        198249.0 / 2500400.0,
+    ],
+    [0.0, 32229.0 / 714400.0, 5220557.0 / 5000800.0],
+];
+
+const XYZ_TO_P3: Matrix = [
+    [
+        446124.0 / 178915.0,
+        -333277.0 / 357830.0,
+        -72051.0 / 178915.0,
+    ],
+    [-14852.0 / 17905.0, 63121.0 / 35810.0, 423.0 / 17905.0],
+    [11844.0 / 330415.0, -50337.0 / 660830.0, 316169.0 / 330415.0],
+];
+
+const REC2020_TO_XYZ: Matrix = [
+    [
+        63426534.0 / 99577255.0,
+        20160776.0 / 139408157.0,
+        47086771.0 / 278816314.0,
+    ],
+    [
+        26158966.0 / 99577255.0,
+        472592308.0 / 697040785.0,
+        8267143.0 / 139408157.0,
+    ],
+    [0.0, 19567812.0 / 697040785.0, 295819943.0 / 278816314.0],
+];
+
+const XYZ_TO_REC2020: Matrix = [
+    [
+        30757411.0 / 17917100.0,
+        -6372589.0 / 17917100.0,
+        -4539589.0 / 17917100.0,
+    ],
+    [
+        -19765991.0 / 29648200.0,
+        47925759.0 / 29648200.0,
+        467509.0 / 29648200.0,
+    ],
+    [
+        792561.0 / 44930125.0,
+        -1921689.0 / 44930125.0,
+        42328811.0 / 44930125.0,
+    ],
+];
+
+fn mul(m: &Matrix, v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+#[inline]
+fn srgb_to_linear(x: f64) -> f64 {
+    if x.abs() >= 0.04045 {
+        return x.signum() * ((x.abs() + 0.055) / 1.055).powf(2.4);
+    }
+    x / 12.92
+}
+
+#[inline]
+fn srgb_from_linear(x: f64) -> f64 {
+    if x.abs() >= 0.0031308 {
+        return x.signum() * (1.055 * x.abs().powf(1.0 / 2.4) - 0.055);
+    }
+    12.92 * x
+}
+
+const REC2020_ALPHA: f64 = 1.09929682680944;
+const REC2020_BETA: f64 = 0.018053968510807;
+
+#[inline]
+fn rec2020_to_linear(x: f64) -> f64 {
+    if x.abs() < REC2020_BETA * 4.5 {
+        return x / 4.5;
+    }
+    x.signum() * ((x.abs() + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+}
+
+#[inline]
+fn rec2020_from_linear(x: f64) -> f64 {
+    if x.abs() < REC2020_BETA {
+        return x * 4.5;
+    }
+    x.signum() * (REC2020_ALPHA * x.abs().powf(0.45) - (REC2020_ALPHA - 1.0))
+}
+
+pub(crate) fn to_linear_rgba(col: &Color) -> [f64; 4] {
+    [
+        srgb_to_linear(col.r),
+        srgb_to_linear(col.g),
+        srgb_to_linear(col.b),
+        col.a,
+    ]
+}
+
+pub(crate) fn from_linear_rgba(r: f64, g: f64, b: f64, a: f64) -> Color {
+    Color::new(
+        srgb_from_linear(r),
+        srgb_from_linear(g),
+        srgb_from_linear(b),
+        a,
+    )
+}
+
+pub(crate) fn to_oklaba(col: &Color) -> [f64; 4] {
+    let [r, g, b, _] = to_linear_rgba(col);
+    let l_ = (0.4121656120 * r + 0.5362752080 * g + 0.0514575653 * b).cbrt();
+    let m_ = (0.2118591070 * r + 0.6807189584 * g + 0.1074065790 * b).cbrt();
+    let s_ = (0.0883097947 * r + 0.2818474174 * g + 0.6302613616 * b).cbrt();
+    [
+        0.2104542553 * l_ + 0.7936177850 * m_ - 0.0040720468 * s_,
+        1.9779984951 * l_ - 2.4285922050 * m_ + 0.4505937099 * s_,
+        0.0259040371 * l_ + 0.7827717662 * m_ - 0.8086757660 * s_,
+        col.a,
+    ]
+}
+
+pub(crate) fn from_oklaba(l: f64, a: f64, b: f64, alpha: f64) -> Color {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+    from_linear_rgba(
+        4.0767245293 * l_ - 3.3072168827 * m_ + 0.2307590544 * s_,
+        -1.2681437731 * l_ + 2.6093323231 * m_ - 0.3411344290 * s_,
+        -0.0041119885 * l_ - 0.7034763098 * m_ + 1.7068625689 * s_,
+        alpha,
+    )
+}
+
+// CIE L*a*b*, D65 white point
+
+const D65: [f64; 3] = [0.3127 / 0.3290, 1.0, (1.0 - 0.3127 - 0.3290) / 0.3290];
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+
+pub(crate) fn to_laba(col: &Color) -> [f64; 4] {
+    let [r, g, b, a] = to_linear_rgba(col);
+    let xyz = mul(&SRGB_TO_XYZ, [r, g, b]);
+
+    let [fx, fy, fz] = [0, 1, 2].map(|i| {
+        let v = xyz[i] / D65[i];
+        if v > LAB_EPSILON {
+            v.cbrt()
+        } else {
+            (LAB_KAPPA * v + 16.0) / 116.0
+        }
+    });
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz), a]
+}
+
+pub(crate) fn from_laba(l: f64, a: f64, b: f64, alpha: f64) -> Color {
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+
+    let finv = |f: f64| {
+        if f.powi(3) > LAB_EPSILON {
+            f.powi(3)
+        } else {
+            (116.0 * f - 16.0) / LAB_KAPPA
+        }
+    };
+    let y = if l > LAB_KAPPA * LAB_EPSILON {
+        fy.powi(3)
+    } else {
+        l / LAB_KAPPA
+    };
+    let xyz = [finv(fx) * D65[0], y, finv(fz) * D65[2]];
+
+    let [r, g, b] = mul(&XYZ_TO_SRGB, xyz);
+    from_linear_rgba(r, g, b, alpha)
+}
+
+/// RGB color spaces
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ColorSpace {
+    /// sRGB, the color space of [`Color`]
+    Srgb,
+    /// sRGB with linear transfer function
+    SrgbLinear,
+    /// Display P3
+    DisplayP3,
+    /// ITU-R BT.2020
+    Rec2020,
+}
+
+/// Color in a specific RGB color space
+///
+/// The channel values are in the range [0..1] for colors inside the color space's
+/// gamut, colors outside the gamut have values outside [0..1].
+///
+/// ```
+/// use colorgrad::{ColorSpace, WideColor};
+///
+/// let p3_red = WideColor::new(ColorSpace::DisplayP3, 1.0, 0.0, 0.0, 1.0);
+/// let c = p3_red.to_color();
+///
+/// // Display P3 red is outside the sRGB gamut
+/// assert!(c.r > 1.0 && c.g < 0.0 && c.b < 0.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WideColor {
+    pub space: ColorSpace,
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl WideColor {
+    pub fn new(space: ColorSpace, r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self { space, r, g, b, a }
+    }
+
+    /// Convert (extended-range) sRGB [`Color`] into the color space
+    pub fn from_color(col: &Color, space: ColorSpace) -> Self {
+        let [r, g, b, a] = to_linear_rgba(col);
+        let lin = [r, g, b];
+
+        let [r, g, b] = match space {
+            ColorSpace::Srgb => [col.r, col.g, col.b],
+            ColorSpace::SrgbLinear => lin,
+            ColorSpace::DisplayP3 => mul(&XYZ_TO_P3, mul(&SRGB_TO_XYZ, lin)).map(srgb_from_linear),
+            ColorSpace::Rec2020 => {
+                mul(&XYZ_TO_REC2020, mul(&SRGB_TO_XYZ, lin)).map(rec2020_from_linear)
+            }
+        };
+
+        Self { space, r, g, b, a }
+    }
+
+    /// Convert into (extended-range) sRGB [`Color`]. The channel values are outside
+    /// [0..1] if the color is outside the sRGB gamut.
+    pub fn to_color(&self) -> Color {
+        let v = [self.r, self.g, self.b];
+
+        let [r, g, b] = match self.space {
+            ColorSpace::Srgb => return Color::new(self.r, self.g, self.b, self.a),
+            ColorSpace::SrgbLinear => v,
+            ColorSpace::DisplayP3 => mul(&XYZ_TO_SRGB, mul(&P3_TO_XYZ, v.map(srgb_to_linear))),
+            ColorSpace::Rec2020 => {
+                mul(&XYZ_TO_SRGB, mul(&REC2020_TO_XYZ, v.map(rec2020_to_linear)))
+            }
+        };
+
+        from_linear_rgba(r, g, b, self.a)
+    }
+
+    /// Convert into another color space
+    pub fn to_space(&self, space: ColorSpace) -> Self {
+        if space == self.space {
+            return *self;
+        }
+        Self::from_color(&self.to_color(), space)
+    }
+
+    /// Returns `true` if the color is inside the gamut of its color space
+    pub fn in_gamut(&self) -> bool {
+        const EPSILON: f64 = 1e-5;
+        [self.r, self.g, self.b]
+            .iter()
+            .all(|v| (-EPSILON..=1.0 + EPSILON).contains(v))
+    }
+
+    pub fn to_array(&self) -> [f64; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
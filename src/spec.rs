@@ -0,0 +1,320 @@
+use std::str::FromStr;
+use std::{error, fmt};
+
+use crate::registry::normalize;
+use crate::{
+    preset_by_name, BlendMode, CustomGradient, CustomGradientError, Gradient, Interpolation,
+    ParseColorError, StepPosition,
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ParseSpecError {
+    Empty,
+    UnknownPreset(String),
+    InvalidColor(String, ParseColorError),
+    /// A color stop with more than two positions
+    InvalidStop(String),
+    InvalidPosition(String),
+    UnknownOption(String),
+    InvalidOption(String),
+    Gradient(CustomGradientError),
+}
+
+impl fmt::Display for ParseSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Empty => f.write_str("empty gradient spec"),
+            Self::UnknownPreset(ref s) => write!(f, "unknown preset '{}'", s),
+            Self::InvalidColor(ref s, ref e) => write!(f, "invalid color '{}': {}", s, e),
+            Self::InvalidStop(ref s) => write!(f, "invalid color stop '{}'", s),
+            Self::InvalidPosition(ref s) => write!(f, "invalid color position '{}'", s),
+            Self::UnknownOption(ref s) => write!(f, "unknown option '{}'", s),
+            Self::InvalidOption(ref s) => write!(f, "invalid option '{}'", s),
+            Self::Gradient(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for ParseSpecError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Self::InvalidColor(_, ref e) => Some(e),
+            Self::Gradient(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CustomGradientError> for ParseSpecError {
+    fn from(e: CustomGradientError) -> Self {
+        Self::Gradient(e)
+    }
+}
+
+// Split at `sep`, except inside parentheses (e.g. "rgb(0, 0, 255)")
+pub(crate) fn split_top_level(s: &str, sep: fn(char) -> bool) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if depth == 0 && sep(c) => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+fn parse_position(s: &str) -> Result<f64, ParseSpecError> {
+    let value = match s.strip_suffix('%') {
+        Some(p) => p.parse::<f64>().map(|v| v / 100.0),
+        None => s.parse::<f64>(),
+    };
+    match value {
+        Ok(v) if v.is_finite() => Ok(v),
+        _ => Err(ParseSpecError::InvalidPosition(s.to_string())),
+    }
+}
+
+// `key=value` option, with optional spaces around `=`
+fn key_value(opt: &str) -> Option<(&str, &str)> {
+    let (key, value) = opt.split_once('=')?;
+    Some((key.trim_end(), value.trim_start()))
+}
+
+fn parse_mode(s: &str) -> Option<BlendMode> {
+    match normalize(s).as_str() {
+        "rgb" | "srgb" => Some(BlendMode::Rgb),
+        "linearrgb" | "srgblinear" => Some(BlendMode::LinearRgb),
+        "hsv" => Some(BlendMode::Hsv),
+        "oklab" => Some(BlendMode::Oklab),
+        _ => None,
+    }
+}
+
+fn parse_interpolation(s: &str) -> Option<Interpolation> {
+    match normalize(s).as_str() {
+        "linear" => Some(Interpolation::Linear),
+        "basis" => Some(Interpolation::Basis),
+        "catmullrom" => Some(Interpolation::CatmullRom),
+        "monotone" | "monotonecubic" => Some(Interpolation::MonotoneCubic),
+        "akima" => Some(Interpolation::Akima),
+        "natural" | "naturalcubic" => Some(Interpolation::NaturalCubic),
+        "step" | "stepleft" => Some(Interpolation::Step(StepPosition::Left)),
+        "stepright" => Some(Interpolation::Step(StepPosition::Right)),
+        "stepcenter" => Some(Interpolation::Step(StepPosition::Center)),
+        _ => None,
+    }
+}
+
+fn parse_sharp(s: &str) -> Option<(usize, f64)> {
+    let args = s.strip_prefix("sharp(")?.strip_suffix(')')?;
+    let mut args = args.split(',').map(str::trim);
+    let segment = args.next()?.parse().ok()?;
+    let smoothness = match args.next() {
+        Some(v) => v.parse().ok()?,
+        None => 0.0,
+    };
+    if args.next().is_some() {
+        return None;
+    }
+    Some((segment, smoothness))
+}
+
+// Color stop list: each stop is a color followed by zero, one or two positions.
+// Missing positions are filled in like CSS gradients do.
+fn parse_stops(s: &str) -> Result<CustomGradient, ParseSpecError> {
+    let mut colors = Vec::new();
+    let mut positions = Vec::new();
+
+    for stop in split_top_level(s, |c| c == ',') {
+        let tokens: Vec<&str> = split_top_level(stop.trim(), char::is_whitespace)
+            .into_iter()
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        if tokens.is_empty() {
+            return Err(ParseSpecError::InvalidColor(
+                stop.trim().to_string(),
+                ParseColorError::InvalidUnknown,
+            ));
+        }
+        if tokens.len() > 3 {
+            return Err(ParseSpecError::InvalidStop(stop.trim().to_string()));
+        }
+
+        let color = csscolorparser::parse(tokens[0])
+            .map_err(|e| ParseSpecError::InvalidColor(tokens[0].to_string(), e))?;
+
+        if tokens.len() == 1 {
+            colors.push(color);
+            positions.push(None);
+        } else {
+            for t in &tokens[1..] {
+                colors.push(color.clone());
+                positions.push(Some(parse_position(t)?));
+            }
+        }
+    }
+
+    let n = positions.len();
+    if positions[0].is_none() {
+        positions[0] = Some(0.0);
+    }
+    if n > 1 && positions[n - 1].is_none() {
+        positions[n - 1] = Some(1.0);
+    }
+
+    // A position can't be less than any position before it
+    let mut max = f64::NEG_INFINITY;
+    for p in positions.iter_mut().flatten() {
+        max = max.max(*p);
+        *p = max;
+    }
+
+    // Spread missing positions evenly between their neighbours
+    let mut i = 0;
+    while i < n {
+        if positions[i].is_some() {
+            i += 1;
+            continue;
+        }
+        let start = i - 1;
+        let mut end = i;
+        while positions[end].is_none() {
+            end += 1;
+        }
+        let (p0, p1) = (positions[start].unwrap(), positions[end].unwrap());
+        for (k, p) in positions[i..end].iter_mut().enumerate() {
+            *p = Some(p0 + (p1 - p0) * (k + 1) as f64 / (end - start) as f64);
+        }
+        i = end;
+    }
+
+    let positions: Vec<f64> = positions.into_iter().flatten().collect();
+    let mut builder = CustomGradient::new();
+    builder.colors(&colors);
+    if colors.len() > 1 {
+        builder.domain(&positions);
+    }
+    Ok(builder)
+}
+
+/// Parse gradient from a spec string
+///
+/// A spec is a preset or a list of colors, followed by options, all separated by `;`.
+///
+/// * Preset: any name accepted by [`preset_by_name()`], `_r` at the end reverses it, e.g.
+///   `"viridis_r"`.
+/// * Colors: comma-separated CSS colors, each optionally followed by one or two positions,
+///   as a percentage or a number, e.g. `"red 0%, gold 30%, #00f"`. Missing positions are
+///   filled in like CSS gradients: evenly spaced between their neighbours.
+/// * `mode=<rgb|linear-rgb|hsv|oklab>`: blend mode of a color list
+/// * `interpolation=<linear|basis|catmull-rom|monotone|akima|natural|step|step-right|step-center>`:
+///   interpolation of a color list
+///
+/// Spaces around the `=` of an option are ignored, e.g. `"mode = oklab"`.
+/// * `sharp(segment, smoothness)`: see [`Gradient::sharp()`], `smoothness` defaults to `0`
+/// * `reverse`
+///
+/// [`Gradient`] also implements [`FromStr`] with this syntax.
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let g = colorgrad::parse_spec("red 0%, gold 30%, #00f; mode=oklab")?;
+/// assert_eq!(g.at(0.3).to_hex_string(), "#ffd700");
+///
+/// let g: colorgrad::Gradient = "rd_bu_r; sharp(5, 0.1)".parse()?;
+///
+/// let err = colorgrad::parse_spec("viridsi").unwrap_err();
+/// assert_eq!(err.to_string(), "unknown preset 'viridsi'");
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_spec(spec: &str) -> Result<Gradient, ParseSpecError> {
+    let mut parts = split_top_level(spec, |c| c == ';')
+        .into_iter()
+        .map(str::trim);
+    let base = parts.next().unwrap_or_default();
+    let options: Vec<&str> = parts.collect();
+
+    if base.is_empty() {
+        return Err(ParseSpecError::Empty);
+    }
+
+    let preset = match preset_by_name(base) {
+        Some(g) => Some(g),
+        None => base
+            .strip_suffix("_r")
+            .and_then(preset_by_name)
+            .map(Gradient::reversed),
+    };
+
+    let mut gradient = match preset {
+        Some(g) => {
+            for opt in options.iter() {
+                if let Some(("mode" | "interpolation", _)) = key_value(opt) {
+                    return Err(ParseSpecError::InvalidOption(opt.to_string()));
+                }
+            }
+            g
+        }
+        None => {
+            let is_name = base.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            let mut builder = match parse_stops(base) {
+                Err(ParseSpecError::InvalidColor(..)) if is_name && !base.contains(',') => {
+                    return Err(ParseSpecError::UnknownPreset(base.to_string()));
+                }
+                result => result?,
+            };
+            for opt in options.iter() {
+                match key_value(opt) {
+                    Some(("mode", v)) => {
+                        let mode = parse_mode(v)
+                            .ok_or_else(|| ParseSpecError::InvalidOption(opt.to_string()))?;
+                        builder.mode(mode);
+                    }
+                    Some(("interpolation", v)) => {
+                        let interpolation = parse_interpolation(v)
+                            .ok_or_else(|| ParseSpecError::InvalidOption(opt.to_string()))?;
+                        builder.interpolation(interpolation);
+                    }
+                    _ => {}
+                }
+            }
+            builder.build()?
+        }
+    };
+
+    for opt in options {
+        if let Some(("mode" | "interpolation", _)) = key_value(opt) {
+            continue;
+        } else if opt.starts_with("sharp(") {
+            let (segment, smoothness) =
+                parse_sharp(opt).ok_or_else(|| ParseSpecError::InvalidOption(opt.to_string()))?;
+            gradient = gradient.sharp(segment, smoothness);
+        } else if opt == "reverse" {
+            gradient = gradient.reversed();
+        } else if !opt.is_empty() {
+            return Err(ParseSpecError::UnknownOption(opt.to_string()));
+        }
+    }
+
+    Ok(gradient)
+}
+
+impl FromStr for Gradient {
+    type Err = ParseSpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_spec(s)
+    }
+}
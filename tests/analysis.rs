@@ -0,0 +1,122 @@
+use colorgrad::{BlendMode, Color, CustomGradient, Interpolation, LabSpace, Monotonicity};
+
+#[test]
+fn analyze_lightness() {
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#fff"])
+        .build()
+        .unwrap();
+
+    let a = g.analyze(11, LabSpace::Oklab);
+    assert_eq!(a.space, LabSpace::Oklab);
+    assert_eq!(a.positions.len(), 11);
+    assert_eq!(a.positions[0], 0.0);
+    assert_eq!(a.positions[10], 1.0);
+    assert_eq!(a.lightness.len(), 11);
+    assert_eq!(a.lightness_monotonicity, Monotonicity::Increasing);
+    assert_eq!(a.grayscale_monotonicity, Monotonicity::Increasing);
+    assert!(a.lightness[0].abs() < 1e-4);
+    assert!((a.lightness[10] - 1.0).abs() < 1e-4);
+
+    let g = CustomGradient::new()
+        .html_colors(&["#fff", "#000"])
+        .build()
+        .unwrap();
+    let a = g.analyze(11, LabSpace::Cielab);
+    assert_eq!(a.lightness_monotonicity, Monotonicity::Decreasing);
+    assert!((a.lightness[0] - 100.0).abs() < 1e-4);
+
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#f00"])
+        .build()
+        .unwrap();
+    let a = g.analyze(11, LabSpace::Oklab);
+    assert_eq!(a.lightness_monotonicity, Monotonicity::Constant);
+    assert_eq!(a.total_delta_e(), 0.0);
+    assert!(a.cyclic);
+
+    // Lightness goes up and down
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#ff0", "#00f"])
+        .build()
+        .unwrap();
+    let a = g.analyze(11, LabSpace::Oklab);
+    assert_eq!(a.lightness_monotonicity, Monotonicity::None);
+    assert_eq!(a.grayscale_monotonicity, Monotonicity::None);
+}
+
+#[test]
+fn analyze_grayscale() {
+    // Blue to yellow, luminance increases
+    let a = CustomGradient::new()
+        .html_colors(&["#00f", "#ff0"])
+        .mode(BlendMode::LinearRgb)
+        .build()
+        .unwrap()
+        .analyze(21, LabSpace::Cielab);
+    assert_eq!(a.grayscale_monotonicity, Monotonicity::Increasing);
+    assert_eq!(a.grayscale.len(), 21);
+
+    // Red to gray with the same luminance
+    let a = CustomGradient::new()
+        .colors(&[
+            Color::new(1.0, 0.0, 0.0, 1.0),
+            Color::from_linear_rgba(0.2126, 0.2126, 0.2126, 1.0),
+        ])
+        .mode(BlendMode::LinearRgb)
+        .build()
+        .unwrap()
+        .analyze(21, LabSpace::Oklab);
+    assert_eq!(a.grayscale_monotonicity, Monotonicity::Constant);
+    assert_ne!(a.lightness_monotonicity, Monotonicity::Constant);
+}
+
+#[test]
+fn analyze_delta_e() {
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#777", "#fff"])
+        .domain(&[0.0, 0.1, 1.0])
+        .mode(BlendMode::Oklab)
+        .build()
+        .unwrap();
+
+    let a = g.analyze(11, LabSpace::Oklab);
+    assert_eq!(a.local_delta_e.len(), 10);
+    assert_eq!(a.cumulative_delta_e.len(), 11);
+    assert_eq!(a.cumulative_delta_e[0], 0.0);
+    assert!((a.total_delta_e() - 1.0).abs() < 1e-3);
+    assert!(!a.cyclic);
+    assert!((a.ends_delta_e - 1.0).abs() < 1e-3);
+
+    // The largest jump is in the first section
+    assert_eq!(a.max_jump.start, 0.0);
+    assert_eq!(a.max_jump.end, 0.1);
+    assert_eq!(a.max_jump.delta_e, a.local_delta_e[0]);
+    assert!(a.local_delta_e[1..].iter().all(|&d| d < a.max_jump.delta_e));
+
+    for (i, d) in a.local_delta_e.iter().enumerate() {
+        let c = a.cumulative_delta_e[i + 1] - a.cumulative_delta_e[i];
+        assert!((c - d).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn analyze_gamut_and_cyclic() {
+    let a = colorgrad::rainbow().analyze(101, LabSpace::Oklab);
+    assert!(a.in_gamut);
+    assert!(a.cyclic);
+    assert!(a.ends_delta_e < 1e-3);
+
+    let a = colorgrad::magma().analyze(101, LabSpace::Oklab);
+    assert!(a.in_gamut);
+    assert!(!a.cyclic);
+
+    let a = CustomGradient::new()
+        .html_colors(&["#000", "#fff", "#fff", "#000"])
+        .interpolation(Interpolation::NaturalCubic)
+        .build()
+        .unwrap()
+        .analyze(101, LabSpace::Oklab);
+    assert!(!a.in_gamut);
+    assert!(a.cyclic);
+}
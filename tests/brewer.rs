@@ -0,0 +1,113 @@
+use colorgrad::brewer::{self, Kind, Suitability};
+
+fn hex(s: &brewer::Scheme) -> Vec<String> {
+    s.colors.iter().map(|c| c.to_hex_string()).collect()
+}
+
+#[test]
+fn brewer_scheme() {
+    let s = brewer::scheme("Blues", 3).unwrap();
+    assert_eq!(s.name, "Blues");
+    assert_eq!(hex(&s), ["#deebf7", "#9ecae1", "#3182bd"]);
+
+    let s = brewer::scheme("blues", 5).unwrap();
+    assert_eq!(s.name, "Blues");
+    assert_eq!(
+        hex(&s),
+        ["#eff3ff", "#bdd7e7", "#6baed6", "#3182bd", "#08519c"]
+    );
+
+    let s = brewer::scheme("RdBu", 4).unwrap();
+    assert_eq!(s.kind, Kind::Diverging);
+    assert_eq!(hex(&s), ["#ca0020", "#f4a582", "#92c5de", "#0571b0"]);
+
+    let s = brewer::scheme("Spectral", 6).unwrap();
+    assert_eq!(
+        hex(&s),
+        ["#d53e4f", "#fc8d59", "#fee08b", "#e6f598", "#99d594", "#3288bd"]
+    );
+
+    // Published quirk: not the same orange as the 9-class scheme
+    let s = brewer::scheme("Oranges", 4).unwrap();
+    assert_eq!(hex(&s)[3], "#d94701");
+
+    let s = brewer::scheme("Set1", 3).unwrap();
+    assert_eq!(s.kind, Kind::Qualitative);
+    assert_eq!(hex(&s), ["#e41a1c", "#377eb8", "#4daf4a"]);
+    assert_eq!(s.palette().len(), 3);
+
+    assert!(brewer::scheme("Blues", 2).is_none());
+    assert!(brewer::scheme("Blues", 10).is_none());
+    assert!(brewer::scheme("Set3", 12).is_some());
+    assert!(brewer::scheme("Set3", 13).is_none());
+    assert!(brewer::scheme("Viridis", 5).is_none());
+}
+
+#[test]
+fn brewer_classes() {
+    assert_eq!(brewer::classes("Greys"), Some(3..=9));
+    assert_eq!(brewer::classes("BrBG"), Some(3..=11));
+    assert_eq!(brewer::classes("Paired"), Some(3..=12));
+    assert_eq!(brewer::classes("Accent"), Some(3..=8));
+    assert_eq!(brewer::classes("foo"), None);
+
+    assert_eq!(brewer::names().len(), 35);
+    assert_eq!(brewer::names_of(Kind::Sequential).len(), 18);
+    assert_eq!(brewer::names_of(Kind::Diverging).len(), 9);
+    assert_eq!(brewer::names_of(Kind::Qualitative).len(), 8);
+
+    for name in brewer::names() {
+        for n in brewer::classes(name).unwrap() {
+            let s = brewer::scheme(name, n).unwrap();
+            assert_eq!(s.colors.len(), n);
+            if s.kind == Kind::Sequential {
+                assert_eq!(s.colorblind_safe, Suitability::Yes);
+            }
+        }
+    }
+}
+
+#[test]
+fn brewer_presets() {
+    // The presets interpolate the largest class scheme
+    let presets = [
+        ("Blues", colorgrad::blues()),
+        ("YlGnBu", colorgrad::yl_gn_bu()),
+        ("RdBu", colorgrad::rd_bu()),
+        ("Spectral", colorgrad::spectral()),
+    ];
+    for (name, g) in presets {
+        let n = *brewer::classes(name).unwrap().end();
+        let s = brewer::scheme(name, n).unwrap();
+        assert_eq!(s.colors.first().unwrap().to_rgba8(), g.at(0.0).to_rgba8());
+        assert_eq!(s.colors.last().unwrap().to_rgba8(), g.at(1.0).to_rgba8());
+    }
+
+    // ... so fewer classes differ from sampling the preset
+    let s = brewer::scheme("Blues", 5).unwrap();
+    assert_ne!(
+        s.colors[0].to_rgba8(),
+        colorgrad::blues().colors(5)[0].to_rgba8()
+    );
+}
+
+#[test]
+fn brewer_flags() {
+    let s = brewer::scheme("Blues", 3).unwrap();
+    assert_eq!(s.print_friendly, Suitability::Yes);
+    assert_eq!(s.photocopy_safe, Suitability::Yes);
+
+    let s = brewer::scheme("Blues", 4).unwrap();
+    assert_eq!(s.print_friendly, Suitability::Maybe);
+    assert_eq!(s.photocopy_safe, Suitability::No);
+
+    let s = brewer::scheme("Set1", 5).unwrap();
+    assert_eq!(s.colorblind_safe, Suitability::Maybe);
+
+    let s = brewer::scheme("Spectral", 7).unwrap();
+    assert_eq!(s.colorblind_safe, Suitability::No);
+
+    let s = brewer::scheme("Dark2", 3).unwrap();
+    assert_eq!(s.colorblind_safe, Suitability::Yes);
+    assert_eq!(s.photocopy_safe, Suitability::No);
+}
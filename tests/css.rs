@@ -0,0 +1,389 @@
+use colorgrad::css::{
+    self, Corner, Direction, Geometry, Length, ParseError, Position, Shape, Size,
+};
+use colorgrad::{ParseColorError, Spread};
+
+#[test]
+fn css_linear() {
+    let g = css::parse("linear-gradient(red, blue)").unwrap();
+    assert_eq!(g.geometry, Geometry::Linear(Direction::Angle(180.0)));
+    assert_eq!(g.spread, Spread::Pad);
+    assert_eq!(g.gradient.domain(), (0.0, 1.0));
+    assert_eq!(g.at(0.5).to_rgba8(), [128, 0, 128, 255]);
+
+    let data = [
+        ("to top", Direction::Angle(0.0)),
+        ("to left", Direction::Angle(270.0)),
+        ("0.25turn", Direction::Angle(90.0)),
+        ("-45deg", Direction::Angle(-45.0)),
+        ("200grad", Direction::Angle(180.0)),
+        ("0", Direction::Angle(0.0)),
+        ("to top right", Direction::Corner(Corner::TopRight)),
+        ("TO LEFT BOTTOM", Direction::Corner(Corner::BottomLeft)),
+    ];
+    for (s, dir) in data {
+        let g = css::parse(&format!("linear-gradient({}, red, blue)", s)).unwrap();
+        assert_eq!(g.geometry, Geometry::Linear(dir));
+    }
+
+    let corner = Direction::Corner(Corner::TopRight);
+    assert!((corner.angle(200.0, 100.0) - 26.565051177).abs() < 1e-6);
+    let corner = Direction::Corner(Corner::BottomLeft);
+    assert!((corner.angle(200.0, 100.0) - 206.565051177).abs() < 1e-6);
+}
+
+#[test]
+fn css_color_stops() {
+    // Missing positions, and positions less than a previous one
+    let g = css::parse("linear-gradient(red 20%, lime, blue, white 80%, black 10%)").unwrap();
+    assert_eq!(g.gradient.domain(), (0.0, 1.0));
+    assert_eq!(g.at(0.1).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(0.4).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(0.6).to_hex_string(), "#0000ff");
+    assert_eq!(g.at(0.79).to_rgba8()[3], 255);
+    assert_eq!(g.at(0.8).to_hex_string(), "#000000");
+    assert_eq!(g.at(1.0).to_hex_string(), "#000000");
+
+    // Double position
+    let g = css::parse("linear-gradient(red 0% 25%, blue 25% 50%, gold 50%)").unwrap();
+    assert_eq!(g.at(0.1).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(0.3).to_hex_string(), "#0000ff");
+    assert_eq!(g.at(0.7).to_hex_string(), "#ffd700");
+
+    // Positions outside [0%..100%]
+    let g = css::parse("linear-gradient(red -50%, blue 150%)").unwrap();
+    assert_eq!(g.gradient.domain(), (-0.5, 1.5));
+    assert_eq!(g.at(0.5).to_rgba8(), [128, 0, 128, 255]);
+
+    // Color hint
+    let g = css::parse("linear-gradient(#000, 20%, #fff)").unwrap();
+    assert_eq!(g.at(0.2).to_rgba8(), [128, 128, 128, 255]);
+    assert_eq!(g.at(0.0).to_hex_string(), "#000000");
+    assert_eq!(g.at(1.0).to_hex_string(), "#ffffff");
+    assert!(g.at(0.1).r > 0.25);
+
+    // Premultiplied alpha
+    let g = css::parse("linear-gradient(red, transparent)").unwrap();
+    assert_eq!(g.at(0.5).to_rgba8(), [255, 0, 0, 128]);
+
+    // Functional colors with commas and spaces
+    let g = css::parse("linear-gradient(rgb(255, 0, 0) 0%, hsl(240 100% 50% / 0.5) 100%)").unwrap();
+    assert_eq!(g.at(1.0).to_rgba8(), [0, 0, 255, 128]);
+}
+
+#[test]
+fn css_interpolation() {
+    let g = css::parse("linear-gradient(in hsl, red, blue)").unwrap();
+    assert_eq!(g.at(0.5).to_hex_string(), "#ff00ff");
+
+    let g = css::parse("linear-gradient(in hsl longer hue, red, blue)").unwrap();
+    assert_eq!(g.at(0.5).to_hex_string(), "#00ff00");
+
+    let g = css::parse("linear-gradient(to right in hsl increasing hue, blue, red)").unwrap();
+    assert_eq!(g.geometry, Geometry::Linear(Direction::Angle(90.0)));
+    assert_eq!(g.at(0.5).to_hex_string(), "#ff00ff");
+
+    let g = css::parse("linear-gradient(in hsl decreasing hue, blue, red)").unwrap();
+    assert_eq!(g.at(0.5).to_hex_string(), "#00ff00");
+
+    // The hue of white is powerless, it takes the hue of blue
+    let g = css::parse("linear-gradient(in oklch, white, blue)").unwrap();
+    let c = g.at(0.5);
+    assert!(c.b > c.r && c.b > c.g);
+
+    let g = css::parse("linear-gradient(in oklab, red, blue)").unwrap();
+    let h = colorgrad::CustomGradient::new()
+        .html_colors(&["red", "blue"])
+        .mode(colorgrad::BlendMode::Oklab)
+        .build()
+        .unwrap();
+    assert_eq!(g.at(0.3).to_rgba8(), h.at(0.3).to_rgba8());
+
+    let g = css::parse("linear-gradient(in srgb-linear, #000, #fff)").unwrap();
+    let h = css::parse("linear-gradient(in xyz, #000, #fff)").unwrap();
+    assert_eq!(g.at(0.5).to_rgba8(), [188, 188, 188, 255]);
+    assert_eq!(g.at(0.5).to_rgba8(), h.at(0.5).to_rgba8());
+
+    for space in [
+        "srgb",
+        "display-p3",
+        "rec2020",
+        "lab",
+        "oklab",
+        "hwb",
+        "lch",
+        "oklch",
+    ] {
+        let g = css::parse(&format!("linear-gradient(in {}, red, blue)", space)).unwrap();
+        assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+        assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+    }
+}
+
+#[test]
+fn css_radial_conic() {
+    let g = css::parse("radial-gradient(red, blue)").unwrap();
+    assert_eq!(
+        g.geometry,
+        Geometry::Radial {
+            shape: Shape::Ellipse,
+            size: Size::FarthestCorner,
+            center: Position::CENTER,
+        }
+    );
+
+    let g = css::parse("radial-gradient(circle closest-side at 20% 80px, red, blue)").unwrap();
+    assert_eq!(
+        g.geometry,
+        Geometry::Radial {
+            shape: Shape::Circle,
+            size: Size::ClosestSide,
+            center: Position {
+                x: Length::Percent(20.0),
+                y: Length::Px(80.0),
+            },
+        }
+    );
+
+    let g = css::parse("radial-gradient(50px 25% at top, red, blue)").unwrap();
+    assert_eq!(
+        g.geometry,
+        Geometry::Radial {
+            shape: Shape::Ellipse,
+            size: Size::Radii(Length::Px(50.0), Length::Percent(25.0)),
+            center: Position {
+                x: Length::Percent(50.0),
+                y: Length::Percent(0.0),
+            },
+        }
+    );
+
+    let g = css::parse("radial-gradient(1in in oklab at bottom left, red, blue)").unwrap();
+    assert_eq!(
+        g.geometry,
+        Geometry::Radial {
+            shape: Shape::Circle,
+            size: Size::Radius(Length::Px(96.0)),
+            center: Position {
+                x: Length::Percent(0.0),
+                y: Length::Percent(100.0),
+            },
+        }
+    );
+
+    let g = css::parse("conic-gradient(from 0.5turn at 25% 75%, red 90deg, blue 50%)").unwrap();
+    assert_eq!(
+        g.geometry,
+        Geometry::Conic {
+            from: 180.0,
+            center: Position {
+                x: Length::Percent(25.0),
+                y: Length::Percent(75.0),
+            },
+        }
+    );
+    assert_eq!(g.at(0.2).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(0.375).to_rgba8(), [128, 0, 128, 255]);
+    assert_eq!(g.at(0.6).to_hex_string(), "#0000ff");
+
+    let g = css::parse("conic-gradient(red, blue)").unwrap();
+    assert_eq!(
+        g.geometry,
+        Geometry::Conic {
+            from: 0.0,
+            center: Position::CENTER,
+        }
+    );
+}
+
+#[test]
+fn css_positions() {
+    let data = [
+        ("center top", 50.0, 0.0),
+        ("top center", 50.0, 0.0),
+        ("center bottom", 50.0, 100.0),
+        ("bottom center", 50.0, 100.0),
+        ("center left", 0.0, 50.0),
+        ("left center", 0.0, 50.0),
+        ("center right", 100.0, 50.0),
+        ("right center", 100.0, 50.0),
+        ("center center", 50.0, 50.0),
+        ("center 20%", 50.0, 20.0),
+        ("20% center", 20.0, 50.0),
+        ("right top", 100.0, 0.0),
+        ("top right", 100.0, 0.0),
+    ];
+    for (pos, x, y) in data {
+        let center = Position {
+            x: Length::Percent(x),
+            y: Length::Percent(y),
+        };
+        let g = css::parse(&format!("radial-gradient(at {}, red, blue)", pos)).unwrap();
+        assert_eq!(
+            g.geometry,
+            Geometry::Radial {
+                shape: Shape::Ellipse,
+                size: Size::FarthestCorner,
+                center,
+            },
+            "{}",
+            pos
+        );
+        let g = css::parse(&format!("conic-gradient(at {}, red, blue)", pos)).unwrap();
+        assert_eq!(g.geometry, Geometry::Conic { from: 0.0, center }, "{}", pos);
+    }
+
+    let g = colorgrad::parse_css("conic-gradient(at center bottom, red, blue)").unwrap();
+    assert_eq!(
+        g.geometry,
+        Geometry::Conic {
+            from: 0.0,
+            center: Position {
+                x: Length::Percent(50.0),
+                y: Length::Percent(100.0),
+            },
+        }
+    );
+    assert_eq!(
+        colorgrad::parse_css("linear").unwrap_err(),
+        colorgrad::ParseCssError::UnknownFunction("linear".to_string())
+    );
+}
+
+#[test]
+fn css_repeating() {
+    let g = css::parse("repeating-linear-gradient(red 10%, blue 30%)").unwrap();
+    assert_eq!(g.spread, Spread::Repeat);
+    assert_eq!(g.gradient.domain(), (0.1, 0.3));
+    assert_eq!(g.at(0.5).to_hex_string(), "#ff0000");
+    let c = g.at(0.2);
+    assert!((c.r - 0.5).abs() < 1e-6 && (c.b - 0.5).abs() < 1e-6);
+    assert_eq!(g.at(0.65).to_rgba8(), g.at(0.25).to_rgba8());
+    assert_eq!(g.at(0.05).to_rgba8(), g.at(0.25).to_rgba8());
+
+    let g = css::parse("repeating-conic-gradient(#000 0 25%, #fff 0 50%)").unwrap();
+    assert_eq!(g.spread, Spread::Repeat);
+    assert_eq!(g.at(0.6).to_hex_string(), "#000000");
+    assert_eq!(g.at(0.9).to_hex_string(), "#ffffff");
+
+    let g = css::parse("repeating-radial-gradient(circle, red, blue)").unwrap();
+    assert_eq!(g.spread, Spread::Repeat);
+
+    // Zero length: the average color
+    let g = css::parse("repeating-linear-gradient(red 50%, blue 50%)").unwrap();
+    assert_eq!(g.at(0.0).to_rgba8(), [128, 0, 128, 255]);
+    assert_eq!(g.at(0.7).to_rgba8(), [128, 0, 128, 255]);
+
+    // Zero length with a color hint at the same position
+    let g = css::parse("repeating-linear-gradient(red 20%, 20%, blue 20%)").unwrap();
+    assert_eq!(g.at(0.3).to_rgba8(), [128, 0, 128, 255]);
+}
+
+#[test]
+fn css_numbers() {
+    let g = css::parse("linear-gradient(red 1e1%, blue 9E+1%)").unwrap();
+    assert_eq!(g.at(0.1).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(0.9).to_hex_string(), "#0000ff");
+
+    let g = css::parse("linear-gradient(2.5e-1turn, red, blue)").unwrap();
+    assert_eq!(g.geometry, Geometry::Linear(Direction::Angle(90.0)));
+
+    let g = css::parse("radial-gradient(circle 1e2px, red, blue)").unwrap();
+    assert_eq!(
+        g.geometry,
+        Geometry::Radial {
+            shape: Shape::Circle,
+            size: Size::Radius(Length::Px(100.0)),
+            center: Position::CENTER,
+        }
+    );
+
+    // "e" followed by a letter is a unit
+    assert_eq!(
+        css::parse("radial-gradient(1em, red, blue)").unwrap_err(),
+        ParseError::UnsupportedUnit("1em".to_string())
+    );
+}
+
+#[test]
+fn css_errors() {
+    let data = [
+        (
+            "linear(red, blue)",
+            ParseError::UnknownFunction("linear".to_string()),
+        ),
+        (
+            "linear-gradient(red, blue",
+            ParseError::UnknownFunction("linear-gradient(red, blue".to_string()),
+        ),
+        (
+            "linear-gradient(red, bleu)",
+            ParseError::InvalidColor("bleu".to_string(), ParseColorError::InvalidUnknown),
+        ),
+        (
+            "linear-gradient(red, blue 10px)",
+            ParseError::UnsupportedUnit("10px".to_string()),
+        ),
+        (
+            "radial-gradient(2em, red, blue)",
+            ParseError::UnsupportedUnit("2em".to_string()),
+        ),
+        (
+            "linear-gradient(in a98-rgb, red, blue)",
+            ParseError::UnsupportedColorSpace("a98-rgb".to_string()),
+        ),
+        (
+            "linear-gradient(in oklab longer hue, red, blue)",
+            ParseError::InvalidGeometry("in oklab longer hue".to_string()),
+        ),
+        (
+            "linear-gradient(to middle, red, blue)",
+            ParseError::InvalidGeometry("to middle".to_string()),
+        ),
+        (
+            "radial-gradient(circle 20%, red, blue)",
+            ParseError::InvalidGeometry("circle 20%".to_string()),
+        ),
+        (
+            "conic-gradient(at 10% 20% 30%, red, blue)",
+            ParseError::InvalidGeometry("at 10% 20% 30%".to_string()),
+        ),
+        (
+            "linear-gradient(red, blue 10% 20% 30%)",
+            ParseError::InvalidStop("blue 10% 20% 30%".to_string()),
+        ),
+        (
+            "linear-gradient(red, 50%)",
+            ParseError::InvalidStop("red, 50%".to_string()),
+        ),
+        (
+            "linear-gradient(red, 20%, 30%, blue)",
+            ParseError::InvalidStop("red, 20%, 30%, blue".to_string()),
+        ),
+        ("linear-gradient()", ParseError::InvalidStop(String::new())),
+        // A misspelled first color is not a geometry
+        (
+            "linear-gradient(rde, blue)",
+            ParseError::InvalidColor("rde".to_string(), ParseColorError::InvalidUnknown),
+        ),
+        (
+            "radial-gradient(rde 10%, blue)",
+            ParseError::InvalidColor("rde".to_string(), ParseColorError::InvalidUnknown),
+        ),
+        (
+            "conic-gradient(rde, blue)",
+            ParseError::InvalidColor("rde".to_string(), ParseColorError::InvalidUnknown),
+        ),
+        (
+            "radial-gradient(circle -10px, red, blue)",
+            ParseError::InvalidGeometry("circle -10px".to_string()),
+        ),
+        (
+            "radial-gradient(10px -5%, red, blue)",
+            ParseError::InvalidGeometry("10px -5%".to_string()),
+        ),
+    ];
+    for (s, err) in data {
+        assert_eq!(css::parse(s).unwrap_err(), err, "{}", s);
+    }
+}
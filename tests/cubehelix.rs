@@ -0,0 +1,105 @@
+use colorgrad::CubehelixGradient;
+
+// Expected values are from matplotlib's `cubehelix` segmentdata functions
+// (`matplotlib._cm._ch_helper`) and from the `cubehelix` Python package
+// (`cubehelix.cmap`), evaluated at the same positions.
+
+#[test]
+fn cubehelix_default() {
+    let g = CubehelixGradient::new().build();
+
+    assert_eq!(g.domain(), (0.0, 1.0));
+    assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 0, 255]);
+    assert_eq!(g.at(0.25).to_rgba8(), [22, 83, 76, 255]);
+    assert_eq!(g.at(0.5).to_rgba8(), [160, 121, 73, 255]);
+    assert_eq!(g.at(0.75).to_rgba8(), [199, 179, 237, 255]);
+    assert_eq!(g.at(1.0).to_rgba8(), [255, 255, 255, 255]);
+
+    assert_eq!(g.at(-1.0).to_rgba8(), [0, 0, 0, 255]);
+    assert_eq!(g.at(2.0).to_rgba8(), [255, 255, 255, 255]);
+    assert_eq!(g.at(f64::NAN).to_rgba8(), [0, 0, 0, 255]);
+}
+
+#[test]
+fn cubehelix_matplotlib() {
+    // matplotlib: cubehelix(gamma=0.8, s=2.0, r=1.0, h=1.5)
+    let g = CubehelixGradient::new()
+        .start(2.0)
+        .rotations(1.0)
+        .hue(1.5)
+        .gamma(0.8)
+        .build();
+
+    assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 0, 255]);
+    assert_eq!(g.at(0.25).to_rgba8(), [41, 93, 156, 255]);
+    assert_eq!(g.at(0.5).to_rgba8(), [215, 103, 193, 255]);
+    assert_eq!(g.at(0.75).to_rgba8(), [234, 196, 149, 255]);
+    assert_eq!(g.at(1.0).to_rgba8(), [255, 255, 255, 255]);
+}
+
+#[test]
+fn cubehelix_lightness_range() {
+    // cubehelix package: cmap(startHue=0.3, rot=-0.5, gamma=1.2, sat=2.0,
+    // minLight=0.1, maxLight=0.85)
+    let g = CubehelixGradient::new()
+        .start(0.3)
+        .rotations(-0.5)
+        .gamma(1.2)
+        .hue(2.0)
+        .lightness(0.1, 0.85)
+        .build();
+
+    assert_eq!(g.at(0.0).to_rgba8(), [22, 8, 44, 255]);
+    assert_eq!(g.at(0.25).to_rgba8(), [29, 56, 141, 255]);
+    assert_eq!(g.at(0.5).to_rgba8(), [15, 135, 183, 255]);
+    assert_eq!(g.at(0.75).to_rgba8(), [47, 208, 170, 255]);
+    assert_eq!(g.at(1.0).to_rgba8(), [153, 245, 177, 255]);
+
+    // Reversed lightness
+    let g = CubehelixGradient::new().lightness(1.0, 0.0).build();
+    assert_eq!(g.at(0.0).to_rgba8(), [255, 255, 255, 255]);
+    assert_eq!(g.at(1.0).to_rgba8(), [0, 0, 0, 255]);
+}
+
+#[test]
+fn cubehelix_grayscale() {
+    let g = CubehelixGradient::new().hue(0.0).build();
+
+    for (i, c) in g.colors(11).iter().enumerate() {
+        let v = i as f64 / 10.0;
+        assert!((c.r - v).abs() < 1e-9);
+        assert!((c.g - v).abs() < 1e-9);
+        assert!((c.b - v).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn cubehelix_presets() {
+    // The presets keep D3's colors, which cap the red and green deviation from gray
+    let g = CubehelixGradient::new().build();
+    let h = colorgrad::cubehelix_default();
+    assert_eq!(g.at(0.25).to_rgba8(), [22, 83, 76, 255]);
+    assert_eq!(h.at(0.25).to_rgba8(), [40, 83, 76, 255]);
+    for t in [0.0, 0.5, 0.75, 1.0] {
+        assert_eq!(g.at(t).to_rgba8(), h.at(t).to_rgba8());
+    }
+
+    // D3's interpolateWarm and interpolateCool
+    let g = CubehelixGradient::new()
+        .start(2.0)
+        .rotations(10.0 / 9.0)
+        .hue_range(1.5, 3.0)
+        .lightness(0.35, 0.8)
+        .build();
+    assert_eq!(g.at(0.0).to_hex_string(), "#6e40aa");
+    assert_eq!(g.at(0.5).to_hex_string(), "#ff5e63");
+    assert_eq!(g.at(1.0).to_hex_string(), "#aff05b");
+    for t in [0.0, 0.3, 0.5, 1.0] {
+        assert_eq!(g.at(t).to_rgba8(), colorgrad::warm().at(t).to_rgba8());
+    }
+
+    let g = colorgrad::cool();
+    assert_eq!(g.at(0.0).to_hex_string(), "#6e40aa");
+    assert_eq!(g.at(0.5).to_rgba8(), [77, 199, 194, 255]);
+    assert_eq!(g.at(1.0).to_hex_string(), "#aff05b");
+}
@@ -0,0 +1,125 @@
+use colorgrad::{Color, CustomGradient, Deficiency};
+
+#[test]
+fn simulate_color() {
+    let colors = [
+        Color::new(1.0, 0.0, 0.0, 1.0),
+        Color::new(0.0, 1.0, 0.0, 0.5),
+        Color::new(0.0, 0.0, 1.0, 1.0),
+        Color::new(0.2, 0.4, 0.6, 1.0),
+    ];
+    let gray = Color::new(0.5, 0.5, 0.5, 1.0);
+
+    for d in [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan] {
+        // Severity 0 is normal vision
+        for c in &colors {
+            assert_eq!(d.simulate(c, 0.0).to_rgba8(), c.to_rgba8());
+            assert_eq!(d.simulate(c, -1.0).to_rgba8(), c.to_rgba8());
+            assert_eq!(d.simulate(c, f64::NAN).to_rgba8(), c.to_rgba8());
+        }
+
+        // Neutral colors are unchanged
+        for s in [0.25, 0.5, 1.0] {
+            let c = d.simulate(&gray, s);
+            assert!((c.r - 0.5).abs() < 1e-3);
+            assert!((c.g - 0.5).abs() < 1e-3);
+            assert!((c.b - 0.5).abs() < 1e-3);
+        }
+
+        // Alpha is unchanged
+        assert_eq!(d.simulate(&colors[1], 1.0).a, 0.5);
+
+        // Severity above 1 is the same as 1
+        assert_eq!(
+            d.simulate(&colors[3], 2.0).to_rgba8(),
+            d.simulate(&colors[3], 1.0).to_rgba8()
+        );
+    }
+
+    // Red and green become almost the same hue for protanopia and deuteranopia
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    assert_eq!(
+        Deficiency::Protan.simulate(&red, 1.0).to_rgba8(),
+        [109, 95, 0, 255]
+    );
+    assert_eq!(
+        Deficiency::Deutan.simulate(&red, 1.0).to_rgba8(),
+        [163, 144, 0, 255]
+    );
+}
+
+#[test]
+fn simulate_severity() {
+    let c = Color::new(0.2, 0.4, 0.6, 1.0);
+    let d = Deficiency::Deutan;
+
+    // Between two table entries
+    let a = d.simulate(&c, 0.5).to_linear_rgba();
+    let b = d.simulate(&c, 0.6).to_linear_rgba();
+    let m = d.simulate(&c, 0.55).to_linear_rgba();
+    assert!(((a.0 + b.0) / 2.0 - m.0).abs() < 1e-9);
+    assert!(((a.1 + b.1) / 2.0 - m.1).abs() < 1e-9);
+    assert!(((a.2 + b.2) / 2.0 - m.2).abs() < 1e-9);
+}
+
+#[test]
+fn simulate_gradient() {
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#0f0"])
+        .build()
+        .unwrap()
+        .simulate_cvd(Deficiency::Deutan, 1.0);
+
+    assert_eq!(g.domain(), (0.0, 1.0));
+    assert_eq!(g.at(0.0).to_rgba8(), [163, 144, 0, 255]);
+
+    for info in colorgrad::presets() {
+        let g = colorgrad::preset_by_name(&info.name).unwrap();
+        let colors = g.colors(11);
+
+        for d in [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan] {
+            // Normal vision
+            let g = colorgrad::preset_by_name(&info.name)
+                .unwrap()
+                .simulate_cvd(d, 0.0);
+            for (a, b) in colors.iter().zip(g.colors(11)) {
+                assert_eq!(a.to_rgba8(), b.to_rgba8(), "{}", info.name);
+            }
+
+            // Full severity: the same as simulating every color
+            let g = colorgrad::preset_by_name(&info.name)
+                .unwrap()
+                .simulate_cvd(d, 1.0);
+            assert_eq!(g.domain(), (0.0, 1.0));
+            for (a, b) in colors.iter().zip(g.colors(11)) {
+                assert_eq!(d.simulate(a, 1.0).to_rgba8(), b.to_rgba8(), "{}", info.name);
+            }
+        }
+    }
+}
+
+#[test]
+fn separation() {
+    for d in [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan] {
+        let s = colorgrad::rainbow().cvd_separation(d, 0.0, 101);
+        assert!((s.ratio - 1.0).abs() < 1e-9);
+        assert!((s.min_local_ratio - 1.0).abs() < 1e-9);
+
+        // Grayscale is not affected
+        let s = colorgrad::greys().cvd_separation(d, 1.0, 101);
+        assert!((s.ratio - 1.0).abs() < 1e-3);
+        assert!((s.min_local_ratio - 1.0).abs() < 1e-3);
+    }
+
+    // Red-green diverging map loses separation with deuteranopia
+    let s = colorgrad::rd_yl_gn().cvd_separation(Deficiency::Deutan, 1.0, 101);
+    assert!(s.min_local_ratio < 0.3);
+
+    let s = colorgrad::cividis().cvd_separation(Deficiency::Deutan, 1.0, 101);
+    assert!(s.min_local_ratio > 0.8);
+
+    // Separation decreases with severity
+    let a = colorgrad::rd_yl_gn().cvd_separation(Deficiency::Deutan, 0.3, 101);
+    let b = colorgrad::rd_yl_gn().cvd_separation(Deficiency::Deutan, 0.9, 101);
+    assert!(a.min_local_ratio > b.min_local_ratio);
+}
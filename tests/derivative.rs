@@ -0,0 +1,190 @@
+use colorgrad::{BlendMode, Color, CustomGradient, DeltaE, Gradient, Interpolation, LabSpace};
+
+// Extended to negative values
+fn to_linear(x: f64) -> f64 {
+    if x.abs() >= 0.04045 {
+        return x.signum() * ((x.abs() + 0.055) / 1.055).powf(2.4);
+    }
+    x / 12.92
+}
+
+// Channel values in the blend mode's color space
+fn channels(c: &Color, mode: BlendMode) -> [f64; 4] {
+    match mode {
+        BlendMode::Rgb => [c.r, c.g, c.b, c.a],
+        BlendMode::LinearRgb => [to_linear(c.r), to_linear(c.g), to_linear(c.b), c.a],
+        BlendMode::Oklab => LabSpace::Oklab.to_lab(c),
+        BlendMode::Hsv => {
+            let (h, s, v, a) = c.to_hsva();
+            [h, s, v, a]
+        }
+    }
+}
+
+// Compare with central differences
+fn check(g: &Gradient, mode: BlendMode) {
+    let h = 1e-6;
+    let tolerance = 1e-4;
+    for t in [0.05, 0.2, 0.37, 0.55, 0.61, 0.8, 0.95] {
+        let d = g.derivative_at(t);
+        assert_eq!(d.mode, mode);
+
+        let c0 = g.at(t - h);
+        let c1 = g.at(t + h);
+        let v0 = channels(&c0, mode);
+        let v1 = channels(&c1, mode);
+
+        for i in 0..4 {
+            let expected = (v1[i] - v0[i]) / (2.0 * h);
+            assert!(
+                (d.channels[i] - expected).abs() < tolerance,
+                "{:?} t={} ch={} {} != {}",
+                mode,
+                t,
+                i,
+                d.channels[i],
+                expected
+            );
+        }
+
+        let speed = DeltaE::Oklab.distance(&c0, &c1) / (2.0 * h);
+        assert!((d.speed - speed).abs() < 1e-4, "{} != {}", d.speed, speed);
+    }
+}
+
+#[test]
+fn derivative_analytic() {
+    let colors = ["#f00", "#ff0", "#0af", "#333", "#a0f8"];
+    let interpolations = [
+        Interpolation::Linear,
+        Interpolation::Basis,
+        Interpolation::CatmullRom,
+        Interpolation::MonotoneCubic,
+        Interpolation::Akima,
+        Interpolation::NaturalCubic,
+    ];
+    let modes = [BlendMode::Rgb, BlendMode::LinearRgb, BlendMode::Oklab];
+
+    for interpolation in interpolations {
+        for mode in modes {
+            let g = CustomGradient::new()
+                .html_colors(&colors)
+                .domain(&[0.0, 0.1, 0.5, 0.7, 1.0])
+                .interpolation(interpolation)
+                .mode(mode)
+                .build()
+                .unwrap();
+            check(&g, mode);
+        }
+    }
+
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#0f0", "#00f"])
+        .mode(BlendMode::Hsv)
+        .build()
+        .unwrap();
+    check(&g, BlendMode::Hsv);
+
+    // Hue goes the short way around
+    let g = CustomGradient::new()
+        .colors(&[
+            Color::from_hsva(350.0, 1.0, 1.0, 1.0),
+            Color::from_hsva(10.0, 1.0, 1.0, 1.0),
+        ])
+        .mode(BlendMode::Hsv)
+        .build()
+        .unwrap();
+    let d = g.derivative_at(0.5);
+    assert!((d.channels[0] - 20.0).abs() < 1e-9);
+
+    // Hard stops
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#ff0", "#0af", "#333"])
+        .domain(&[0.0, 0.4, 0.4, 1.0])
+        .interpolation(Interpolation::CatmullRom)
+        .build()
+        .unwrap();
+    check(&g, BlendMode::Rgb);
+
+    // Separate alpha interpolation
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#ff0", "#0af", "#333"])
+        .interpolation(Interpolation::Basis)
+        .alpha_interpolation(Interpolation::Linear)
+        .opacity(&[(0.0, 1.0), (0.3, 0.2), (1.0, 0.9)])
+        .build()
+        .unwrap();
+    check(&g, BlendMode::Rgb);
+}
+
+#[test]
+fn derivative_linear() {
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#f00", "#f0f"])
+        .domain(&[0.0, 10.0, 20.0])
+        .build()
+        .unwrap();
+
+    assert_eq!(g.derivative_at(0.0).channels, [0.1, 0.0, 0.0, 0.0]);
+    assert_eq!(g.derivative_at(5.0).channels, [0.1, 0.0, 0.0, 0.0]);
+    assert_eq!(g.derivative_at(15.0).channels, [0.0, 0.0, 0.1, 0.0]);
+    assert_eq!(g.derivative_at(20.0).channels, [0.0, 0.0, 0.1, 0.0]);
+
+    // Outside the domain
+    for t in [-1.0, 21.0] {
+        let d = g.derivative_at(t);
+        assert_eq!(d.channels, [0.0; 4]);
+        assert_eq!(d.speed, 0.0);
+    }
+
+    let d = g.derivative_at(f64::NAN);
+    assert!(d.speed.is_nan());
+
+    // Hard stops, zero-length segments are skipped
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#00f", "#0f0"])
+        .domain(&[0.0, 1.0, 1.0])
+        .build()
+        .unwrap();
+    assert_eq!(g.derivative_at(1.0).channels, [-1.0, 0.0, 1.0, 0.0]);
+    assert!(g.derivative_at(1.0).speed.is_finite());
+
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#00f", "#0f0"])
+        .domain(&[0.0, 0.0, 1.0])
+        .build()
+        .unwrap();
+    assert_eq!(g.derivative_at(0.0).channels, [0.0, 1.0, -1.0, 0.0]);
+
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#f00", "#0f0", "#00f", "#fff"])
+        .domain(&[0.0, 10.0, 10.0, 10.0, 20.0])
+        .build()
+        .unwrap();
+    for t in [0.0, 5.0, 10.0, 15.0, 20.0] {
+        assert!(g.derivative_at(t).speed.is_finite(), "t={}", t);
+    }
+    assert_eq!(g.derivative_at(10.0).channels, [0.1, 0.1, 0.0, 0.0]);
+}
+
+#[test]
+fn derivative_numerical() {
+    let presets = [
+        colorgrad::viridis(),
+        colorgrad::rainbow(),
+        colorgrad::sinebow(),
+        colorgrad::cubehelix_default(),
+    ];
+
+    for g in presets {
+        check(&g, BlendMode::Rgb);
+
+        // One-sided at the ends of the domain
+        assert!(g.derivative_at(0.0).speed > 0.0);
+        assert!(g.derivative_at(1.0).speed > 0.0);
+    }
+
+    let g = colorgrad::rainbow().sharp(5, 0.0);
+    assert_eq!(g.derivative_at(0.1).channels, [0.0; 4]);
+    assert_eq!(g.derivative_at(0.1).speed, 0.0);
+}
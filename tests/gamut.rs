@@ -0,0 +1,76 @@
+use colorgrad::{BlendMode, CustomGradient, GamutMapping, Interpolation};
+
+#[test]
+fn gamut_check() {
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#0f0", "#00f"])
+        .build()
+        .unwrap();
+    assert!(g.in_gamut());
+
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#808080", "#fff"])
+        .mode(BlendMode::Oklab)
+        .build()
+        .unwrap();
+    assert!(g.in_gamut());
+
+    assert!(colorgrad::viridis().in_gamut());
+    assert!(colorgrad::rainbow().in_gamut());
+
+    // Oklab blending between green and blue leaves the sRGB gamut
+    let g = CustomGradient::new()
+        .html_colors(&["#0f0", "#00f"])
+        .mode(BlendMode::Oklab)
+        .build()
+        .unwrap();
+    assert!(!g.in_gamut());
+
+    // Spline overshoot
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#fff", "#fff", "#000"])
+        .interpolation(Interpolation::NaturalCubic)
+        .build()
+        .unwrap();
+    assert!(!g.in_gamut());
+}
+
+#[test]
+fn gamut_mapping() {
+    let build = || {
+        CustomGradient::new()
+            .html_colors(&["#0f0", "#00f"])
+            .mode(BlendMode::Oklab)
+            .build()
+            .unwrap()
+    };
+
+    let g = build().gamut_map(GamutMapping::None);
+    assert!(!g.in_gamut());
+
+    let g = build().gamut_map(GamutMapping::Clip);
+    assert!(g.in_gamut());
+    assert_eq!(g.at(0.0).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+
+    let g = build().gamut_map(GamutMapping::OklchChroma);
+    assert!(g.in_gamut());
+    assert_eq!(g.at(0.0).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+
+    // Chroma reduction keeps the lightness and hue closer than clipping
+    let unmapped = build();
+    let clipped = build().gamut_map(GamutMapping::Clip);
+    let mapped = build().gamut_map(GamutMapping::OklchChroma);
+
+    for t in [0.25, 0.5, 0.75] {
+        let (l0, a0, b0, _) = unmapped.at(t).to_oklaba();
+        let (l1, a1, b1, _) = clipped.at(t).to_oklaba();
+        let (l2, a2, b2, _) = mapped.at(t).to_oklaba();
+
+        let hue = |a: f64, b: f64| b.atan2(a);
+        assert!((l2 - l0).abs() < 0.02);
+        assert!((l2 - l0).abs() < (l1 - l0).abs());
+        assert!((hue(a2, b2) - hue(a0, b0)).abs() < (hue(a1, b1) - hue(a0, b0)).abs());
+    }
+}
@@ -1,4 +1,4 @@
-use colorgrad::{BlendMode, Color, CustomGradient, Interpolation};
+use colorgrad::{BlendMode, Color, CustomGradient, Interpolation, StepPosition};
 
 #[test]
 fn custom_gradient() {
@@ -145,6 +145,337 @@ fn custom_gradient_interpolation_mode() {
     assert_eq!(g.at(0.0).to_rgba8(), [255, 0, 0, 255]);
     assert!(g.at(0.5).to_rgba8() != [255, 255, 0, 255]);
     assert_eq!(g.at(1.0).to_rgba8(), [0, 0, 255, 255]);
+
+    // Interpolation monotone cubic, akima and natural cubic
+    for mode in [
+        Interpolation::MonotoneCubic,
+        Interpolation::Akima,
+        Interpolation::NaturalCubic,
+    ] {
+        let g = CustomGradient::new()
+            .html_colors(&["#f00", "#ff0", "#00f"])
+            .domain(&[0.0, 0.3, 1.0])
+            .interpolation(mode)
+            .build()
+            .unwrap();
+        assert_eq!(g.at(0.0).to_rgba8(), [255, 0, 0, 255]);
+        assert_eq!(g.at(0.3).to_rgba8(), [255, 255, 0, 255]);
+        assert_eq!(g.at(1.0).to_rgba8(), [0, 0, 255, 255]);
+    }
+}
+
+#[test]
+fn custom_gradient_catmull_rom_params() {
+    let build = |alpha, tension| {
+        CustomGradient::new()
+            .html_colors(&["#000", "#222", "#ccc", "#eee"])
+            .domain(&[0.0, 0.2, 0.3, 1.0])
+            .interpolation(Interpolation::CatmullRom)
+            .catmull_rom(alpha, tension)
+            .build()
+            .unwrap()
+    };
+
+    let default = CustomGradient::new()
+        .html_colors(&["#000", "#222", "#ccc", "#eee"])
+        .domain(&[0.0, 0.2, 0.3, 1.0])
+        .interpolation(Interpolation::CatmullRom)
+        .build()
+        .unwrap();
+    let centripetal = build(0.5, 0.0);
+    let uniform = build(0.0, 0.0);
+    let chordal = build(1.0, 0.0);
+    let tight = build(0.5, 1.0);
+
+    for g in [&centripetal, &uniform, &chordal, &tight] {
+        assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 0, 255]);
+        assert_eq!(g.at(0.2).to_rgba8(), [34, 34, 34, 255]);
+        assert_eq!(g.at(0.3).to_rgba8(), [204, 204, 204, 255]);
+        assert_eq!(g.at(1.0).to_rgba8(), [238, 238, 238, 255]);
+    }
+
+    for t in [0.1, 0.25, 0.5, 0.9] {
+        assert_eq!(default.at(t).to_rgba8(), centripetal.at(t).to_rgba8());
+    }
+
+    assert_ne!(uniform.at(0.15).to_rgba8(), centripetal.at(0.15).to_rgba8());
+    assert_ne!(chordal.at(0.15).to_rgba8(), centripetal.at(0.15).to_rgba8());
+
+    // Maximum tension gives zero tangents
+    assert_eq!(tight.at(0.65).to_rgba8(), [221, 221, 221, 255]);
+
+    // Out of range values are clamped, NaN gives the default
+    let data = [
+        (build(-1.0, 0.0), &uniform),
+        (build(2.0, 0.0), &chordal),
+        (build(0.5, 5.0), &tight),
+        (build(0.5, -0.5), &centripetal),
+        (build(f64::NAN, 0.0), &centripetal),
+        (build(0.5, f64::NAN), &centripetal),
+        (build(f64::NAN, f64::NAN), &default),
+        (build(f64::INFINITY, f64::NEG_INFINITY), &chordal),
+    ];
+    for (g, expected) in data {
+        for t in [0.1, 0.15, 0.25, 0.5, 0.65, 0.9] {
+            assert_eq!(g.at(t).to_rgba8(), expected.at(t).to_rgba8());
+        }
+    }
+}
+
+#[test]
+fn custom_gradient_step() {
+    let build = |step| {
+        CustomGradient::new()
+            .html_colors(&["#f00", "#0f0", "#00f", "#ff0"])
+            .domain(&[0.0, 10.0, 25.0, 100.0])
+            .mode(BlendMode::Oklab)
+            .interpolation(Interpolation::Step(step))
+            .build()
+            .unwrap()
+    };
+
+    let g = build(StepPosition::Left);
+    assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(9.9).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(10.0).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(24.9).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(25.0).to_hex_string(), "#0000ff");
+    assert_eq!(g.at(99.9).to_hex_string(), "#0000ff");
+    assert_eq!(g.at(100.0).to_hex_string(), "#ffff00");
+
+    let g = build(StepPosition::Right);
+    assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(0.1).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(10.0).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(10.1).to_hex_string(), "#0000ff");
+    assert_eq!(g.at(25.0).to_hex_string(), "#0000ff");
+    assert_eq!(g.at(25.1).to_hex_string(), "#ffff00");
+    assert_eq!(g.at(100.0).to_hex_string(), "#ffff00");
+
+    let g = build(StepPosition::Center);
+    assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(4.9).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(5.0).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(17.4).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(17.5).to_hex_string(), "#0000ff");
+    assert_eq!(g.at(62.4).to_hex_string(), "#0000ff");
+    assert_eq!(g.at(62.5).to_hex_string(), "#ffff00");
+    assert_eq!(g.at(100.0).to_hex_string(), "#ffff00");
+
+    assert_eq!(g.at(-1.0).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(101.0).to_hex_string(), "#ffff00");
+    assert_eq!(g.at(f64::NAN).to_hex_string(), "#000000");
+}
+
+#[test]
+fn custom_gradient_hard_stop() {
+    let modes = [
+        Interpolation::Linear,
+        Interpolation::Basis,
+        Interpolation::CatmullRom,
+        Interpolation::MonotoneCubic,
+        Interpolation::Akima,
+        Interpolation::NaturalCubic,
+        Interpolation::Step(StepPosition::Left),
+        Interpolation::Step(StepPosition::Right),
+        Interpolation::Step(StepPosition::Center),
+    ];
+
+    for interpolation in modes {
+        // Hard stop in the middle
+        let g = CustomGradient::new()
+            .html_colors(&["#f00", "#ff0", "#0f0", "#00f"])
+            .domain(&[0.0, 0.5, 0.5, 1.0])
+            .interpolation(interpolation)
+            .build()
+            .unwrap();
+        assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+        assert_eq!(g.at(0.5).to_hex_string(), "#00ff00");
+        assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+        assert_eq!(g.at(f64::NAN).to_hex_string(), "#000000");
+
+        for t in [0.1, 0.2, 0.3, 0.4, 0.49] {
+            let c = g.at(t);
+            assert!(c.r > 0.99 && c.b < 0.01, "{:?} {}", interpolation, t);
+        }
+        for t in [0.51, 0.6, 0.7, 0.8, 0.9] {
+            let c = g.at(t);
+            assert!(c.r < 0.01, "{:?} {}", interpolation, t);
+        }
+
+        // Three colors at the same position
+        let g = CustomGradient::new()
+            .html_colors(&["#f00", "#ff0", "#fff", "#0f0", "#00f"])
+            .domain(&[0.0, 0.5, 0.5, 0.5, 1.0])
+            .interpolation(interpolation)
+            .build()
+            .unwrap();
+        assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+        assert_eq!(g.at(0.5).to_hex_string(), "#00ff00");
+        assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+
+        // Hard stop at domain min
+        let g = CustomGradient::new()
+            .html_colors(&["#f00", "#0f0", "#00f"])
+            .domain(&[0.0, 0.0, 1.0])
+            .interpolation(interpolation)
+            .build()
+            .unwrap();
+        assert_eq!(g.domain(), (0.0, 1.0));
+        assert_eq!(g.at(-0.1).to_hex_string(), "#ff0000");
+        assert_eq!(g.at(0.0).to_hex_string(), "#00ff00");
+        assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+        assert_eq!(g.at(1.1).to_hex_string(), "#0000ff");
+
+        // Hard stop at domain max
+        let g = CustomGradient::new()
+            .html_colors(&["#f00", "#0f0", "#00f"])
+            .domain(&[0.0, 1.0, 1.0])
+            .interpolation(interpolation)
+            .build()
+            .unwrap();
+        assert_eq!(g.domain(), (0.0, 1.0));
+        assert_eq!(g.at(-0.1).to_hex_string(), "#ff0000");
+        assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+        assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+        assert_eq!(g.at(1.1).to_hex_string(), "#0000ff");
+        assert_eq!(g.repeat_at(0.0).to_hex_string(), "#ff0000");
+
+        // Zero-width domain, the first color up to it and the last color after it
+        let g = CustomGradient::new()
+            .html_colors(&["#f00", "#0f0", "#00f"])
+            .domain(&[0.5, 0.5, 0.5])
+            .interpolation(interpolation)
+            .build()
+            .unwrap();
+        assert_eq!(g.domain(), (0.5, 0.5));
+        assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+        assert_eq!(g.at(0.5).to_hex_string(), "#ff0000");
+        assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+        assert_eq!(g.at(f64::NAN).to_hex_string(), "#000000");
+    }
+
+    // Linear blending on both sides of the hard stop
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#fff", "#000", "#fff"])
+        .domain(&[0.0, 0.5, 0.5, 1.0])
+        .build()
+        .unwrap();
+    assert_eq!(g.at(0.25).to_hex_string(), "#808080");
+    assert_eq!(g.at(0.75).to_hex_string(), "#808080");
+}
+
+#[test]
+fn custom_gradient_alpha() {
+    // Spline color, linear alpha
+    let g = CustomGradient::new()
+        .html_colors(&["#ff0000", "#ffff0000", "#00ff00", "#0000ff80", "#0000ff"])
+        .interpolation(Interpolation::CatmullRom)
+        .alpha_interpolation(Interpolation::Linear)
+        .build()
+        .unwrap();
+
+    let spline = CustomGradient::new()
+        .html_colors(&["#ff0000", "#ffff0000", "#00ff00", "#0000ff80", "#0000ff"])
+        .interpolation(Interpolation::CatmullRom)
+        .build()
+        .unwrap();
+
+    for t in [0.1, 0.3, 0.6, 0.9] {
+        let (a, b) = (g.at(t), spline.at(t));
+        assert_eq!([a.r, a.g, a.b], [b.r, b.g, b.b]);
+    }
+
+    assert_eq!(g.at(0.125).a, 0.5);
+    assert_eq!(g.at(0.25).a, 0.0);
+    assert_eq!(g.at(0.375).a, 0.5);
+    for i in 0..=100 {
+        let a = g.at(i as f64 / 100.0).a;
+        assert!((0.0..=1.0).contains(&a));
+    }
+
+    // Opacity stops independent of the color stops
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#00f"])
+        .domain(&[0.0, 100.0])
+        .interpolation(Interpolation::MonotoneCubic)
+        .opacity(&[(0.0, 1.0), (30.0, 0.0), (100.0, 1.0)])
+        .build()
+        .unwrap();
+    assert_eq!(g.domain(), (0.0, 100.0));
+    assert_eq!(g.at(0.0).to_rgba8(), [255, 0, 0, 255]);
+    assert_eq!(g.at(30.0).a, 0.0);
+    assert_eq!(g.at(100.0).to_rgba8(), [0, 0, 255, 255]);
+    assert!(g.at(15.0).a < 1.0);
+
+    // Opacity stops with hard stop and custom interpolation
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#0f0", "#00f"])
+        .opacity(&[(0.0, 1.0), (0.5, 1.0), (0.5, 0.2), (1.0, 0.2)])
+        .alpha_interpolation(Interpolation::Linear)
+        .build()
+        .unwrap();
+    assert_eq!(g.at(0.25).to_rgba8(), [128, 128, 0, 255]);
+    assert_eq!(g.at(0.5).to_rgba8(), [0, 255, 0, 51]);
+    assert_eq!(g.at(0.75).to_rgba8(), [0, 128, 128, 51]);
+
+    // Single opacity stop
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#0f0", "#00f"])
+        .opacity(&[(0.3, 0.5)])
+        .build()
+        .unwrap();
+    assert_eq!(g.at(0.0).to_rgba8(), [255, 0, 0, 128]);
+    assert_eq!(g.at(1.0).to_rgba8(), [0, 0, 255, 128]);
+
+    // Wrong opacity stops
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#0f0", "#00f"])
+        .opacity(&[(0.5, 1.0), (0.2, 0.0)])
+        .build();
+    assert_eq!(g.unwrap_err().to_string(), "wrong domain");
+}
+
+#[test]
+fn custom_gradient_no_overshoot() {
+    let colors = ["#000", "#111", "#eee", "#fff", "#fff", "#808080"];
+    let pos = [0.0, 0.1, 0.5, 0.6, 0.8, 1.0];
+
+    // Monotone cubic stays between the adjacent stops
+    let g = CustomGradient::new()
+        .html_colors(&colors)
+        .domain(&pos)
+        .interpolation(Interpolation::MonotoneCubic)
+        .build()
+        .unwrap();
+
+    for (i, p) in pos.windows(2).enumerate() {
+        let a = Color::from_html(colors[i]).unwrap().r;
+        let b = Color::from_html(colors[i + 1]).unwrap().r;
+        for j in 0..=20 {
+            let r = g.at(p[0] + (p[1] - p[0]) * j as f64 / 20.0).r;
+            assert!(r >= a.min(b) - 1e-9 && r <= a.max(b) + 1e-9);
+        }
+    }
+
+    // Catmull-Rom overshoots on the same data
+    let g = CustomGradient::new()
+        .html_colors(&colors)
+        .domain(&pos)
+        .interpolation(Interpolation::CatmullRom)
+        .build()
+        .unwrap();
+    assert!((0..=20).any(|j| g.at(0.6 + 0.01 * j as f64).r > 1.0));
+
+    // Akima keeps flat runs flat
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#888", "#888", "#888", "#fff"])
+        .interpolation(Interpolation::Akima)
+        .build()
+        .unwrap();
+    for j in 0..=20 {
+        assert_eq!(g.at(0.25 + 0.025 * j as f64).to_hex_string(), "#888888");
+    }
 }
 
 #[test]
@@ -402,4 +733,185 @@ fn cyclic() {
 
     let g = colorgrad::sinebow();
     assert_eq!(g.at(0.0).to_rgba8(), g.at(1.0).to_rgba8());
+
+    let g = colorgrad::sinebow_with(0.5, 2.0);
+    assert_eq!(g.at(0.0).to_rgba8(), g.at(1.0).to_rgba8());
+
+    let cyclic = [
+        colorgrad::rainbow_with(37.3, (0.8, 0.8), (0.6, 0.6)),
+        colorgrad::sinebow(),
+        colorgrad::sinebow_with(0.3, 3.0),
+        colorgrad::phase_wheel(),
+        colorgrad::phase_wheel().uniformized(colorgrad::DeltaE::Oklab),
+        colorgrad::phase_wheel()
+            .linearize_lightness(colorgrad::LabSpace::Oklab)
+            .gradient,
+        colorgrad::sinebow().simulate_cvd(colorgrad::Deficiency::Deutan, 1.0),
+        colorgrad::sinebow().gamut_map(colorgrad::GamutMapping::Clip),
+        colorgrad::sinebow().sharp(7, 0.2),
+        colorgrad::sinebow().reversed(),
+    ];
+    for g in cyclic {
+        assert!(g.is_cyclic());
+        // Exactly equal, not just after rounding
+        assert_eq!(g.at(0.0), g.at(1.0));
+        assert_eq!(g.repeat_at(0.0), g.repeat_at(1.0));
+        assert_seamless(&g);
+    }
+
+    // Same color at both ends, but the direction changes there
+    let mirrored = [
+        colorgrad::rainbow(),
+        colorgrad::rainbow_with(37.3, (0.5, 1.0), (0.4, 0.7)),
+        colorgrad::turbo_with(0.1, 0.9, true),
+        colorgrad::cividis_with(0.0, 1.0, true),
+    ];
+    for g in mirrored {
+        assert!(!g.is_cyclic());
+        assert_eq!(g.at(0.0), g.at(1.0));
+    }
+
+    let not_cyclic = [
+        colorgrad::viridis(),
+        colorgrad::turbo(),
+        colorgrad::sinebow_with(0.0, 1.5),
+        colorgrad::CubehelixGradient::new().build(),
+        colorgrad::viridis().sharp(7, 0.2),
+        CustomGradient::new().build().unwrap(),
+    ];
+    for g in not_cyclic {
+        assert!(!g.is_cyclic());
+    }
+
+    // Every cyclic preset
+    for info in colorgrad::presets() {
+        let g = colorgrad::preset_by_name(&info.name).unwrap();
+        if g.is_cyclic() {
+            assert_eq!(g.at(0.0), g.at(1.0), "{}", info.name);
+            assert_seamless(&g);
+        }
+    }
+}
+
+// The rate of change is the same on both sides of the seam
+fn assert_seamless(g: &colorgrad::Gradient) {
+    let d0 = g.derivative_at(0.0);
+    let d1 = g.derivative_at(1.0);
+    for (a, b) in d0.channels.iter().zip(d1.channels.iter()) {
+        assert!((a - b).abs() < 1e-3, "{:?} {:?}", d0.channels, d1.channels);
+    }
+    assert!(
+        (d0.speed - d1.speed).abs() < 1e-3,
+        "{} {}",
+        d0.speed,
+        d1.speed
+    );
+}
+
+#[test]
+fn phase_wheel() {
+    let g = colorgrad::phase_wheel();
+    assert_eq!(g.domain(), (0.0, 1.0));
+    assert_eq!(g.at(f64::NAN).to_rgba8(), [0, 0, 0, 255]);
+
+    let a = g.analyze(361, colorgrad::LabSpace::Oklab);
+    assert!(a.in_gamut);
+    assert!(a.cyclic);
+    for l in a.lightness.iter() {
+        assert!((l - 0.75).abs() < 1e-3);
+    }
+    // Perceptually uniform
+    let mean = a.total_delta_e() / 360.0;
+    for d in a.local_delta_e.iter().skip(1) {
+        assert!((d - mean).abs() < mean * 0.01);
+    }
+}
+
+#[test]
+fn preset_with() {
+    let a = colorgrad::sinebow();
+    let b = colorgrad::sinebow_with(0.0, 1.0);
+    for t in [0.0, 0.1, 0.33, 0.5, 0.9, 1.0] {
+        assert_eq!(a.at(t).to_rgba8(), b.at(t).to_rgba8());
+    }
+
+    let g = colorgrad::sinebow_with(0.3, 3.0);
+    assert_eq!(g.at(0.0).to_rgba8(), g.at(1.0).to_rgba8());
+    assert_eq!(g.at(0.0).to_rgba8(), a.at(0.3).to_rgba8());
+    assert_eq!(g.at(0.1).to_rgba8(), a.at(0.6).to_rgba8());
+
+    let a = colorgrad::rainbow();
+    let b = colorgrad::rainbow_with(0.0, (0.75, 1.5), (0.35, 0.8));
+    for t in [0.0, 0.1, 0.33, 0.5, 0.9, 1.0] {
+        assert_eq!(a.at(t).to_rgba8(), b.at(t).to_rgba8());
+    }
+
+    let g = colorgrad::rainbow_with(360.0, (0.75, 1.5), (0.35, 0.8));
+    assert_eq!(g.at(0.4).to_rgba8(), a.at(0.4).to_rgba8());
+
+    let g = colorgrad::rainbow_with(120.0, (0.5, 0.5), (0.6, 0.6));
+    assert_eq!(g.at(0.0).to_rgba8(), g.at(1.0).to_rgba8());
+    assert_ne!(g.at(0.0).to_rgba8(), a.at(0.0).to_rgba8());
+
+    for (a, b) in [
+        (colorgrad::turbo(), colorgrad::turbo_with(0.0, 1.0, false)),
+        (
+            colorgrad::cividis(),
+            colorgrad::cividis_with(0.0, 1.0, false),
+        ),
+    ] {
+        for t in [0.0, 0.1, 0.33, 0.5, 0.9, 1.0] {
+            assert_eq!(a.at(t).to_rgba8(), b.at(t).to_rgba8());
+        }
+    }
+
+    let a = colorgrad::turbo();
+
+    let g = colorgrad::turbo_with(0.2, 0.8, false);
+    assert_eq!(g.at(0.0).to_rgba8(), a.at(0.2).to_rgba8());
+    assert_eq!(g.at(0.5).to_rgba8(), a.at(0.5).to_rgba8());
+    assert_eq!(g.at(1.0).to_rgba8(), a.at(0.8).to_rgba8());
+
+    let g = colorgrad::turbo_with(1.0, 0.0, false);
+    assert_eq!(g.at(0.0).to_rgba8(), a.at(1.0).to_rgba8());
+    assert_eq!(g.at(1.0).to_rgba8(), a.at(0.0).to_rgba8());
+
+    let g = colorgrad::turbo_with(0.0, 1.0, true);
+    assert_eq!(g.at(0.0).to_rgba8(), g.at(1.0).to_rgba8());
+    assert_eq!(g.at(0.5).to_rgba8(), a.at(1.0).to_rgba8());
+    assert_eq!(g.at(0.25).to_rgba8(), g.at(0.75).to_rgba8());
+
+    let g = colorgrad::cividis_with(0.1, 0.9, true);
+    assert_eq!(
+        g.at(0.0).to_rgba8(),
+        colorgrad::cividis().at(0.1).to_rgba8()
+    );
+    assert_eq!(g.at(f64::NAN).to_rgba8(), [0, 0, 0, 255]);
+}
+
+#[test]
+fn listed() {
+    let table: Vec<_> = (0..=255)
+        .map(|i| Color::from_rgba8(i as u8, i as u8, 255 - i as u8, 255))
+        .collect();
+    let g = colorgrad::listed(&table);
+
+    assert_eq!(g.domain(), (0.0, 1.0));
+    assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 255, 255]);
+    assert_eq!(g.at(0.5).to_rgba8(), [128, 128, 127, 255]);
+    assert_eq!(g.at(0.499).to_rgba8(), [127, 127, 128, 255]);
+    assert_eq!(g.at(1.0).to_rgba8(), [255, 255, 0, 255]);
+    assert_eq!(g.at(-1.0).to_rgba8(), [0, 0, 255, 255]);
+    assert_eq!(g.at(2.0).to_rgba8(), [255, 255, 0, 255]);
+    assert_eq!(g.at(f64::NAN).to_rgba8(), [0, 0, 0, 255]);
+
+    // Every entry is reached at the center of its bin
+    for (i, c) in table.iter().enumerate() {
+        let t = (i as f64 + 0.5) / 256.0;
+        assert_eq!(g.at(t).to_rgba8(), c.to_rgba8());
+    }
+
+    let g = colorgrad::listed(&[]);
+    assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 0, 255]);
+    assert_eq!(g.at(1.0).to_rgba8(), [255, 255, 255, 255]);
 }
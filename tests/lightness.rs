@@ -0,0 +1,125 @@
+use colorgrad::{Color, CustomGradient, DeltaE, LabSpace};
+
+fn is_linear(values: &[f64], tolerance: f64) -> bool {
+    let n = values.len() - 1;
+    let (l0, l1) = (values[0], values[n]);
+    values
+        .iter()
+        .enumerate()
+        .all(|(i, l)| (l - (l0 + (l1 - l0) * i as f64 / n as f64)).abs() < tolerance)
+}
+
+#[test]
+fn lab_space() {
+    let black = Color::new(0.0, 0.0, 0.0, 1.0);
+    let white = Color::new(1.0, 1.0, 1.0, 1.0);
+
+    assert!(LabSpace::Oklab.lightness(&black).abs() < 1e-6);
+    assert!((LabSpace::Oklab.lightness(&white) - 1.0).abs() < 1e-4);
+    assert!(LabSpace::Cielab.lightness(&black).abs() < 1e-6);
+    assert!((LabSpace::Cielab.lightness(&white) - 100.0).abs() < 1e-6);
+}
+
+#[test]
+fn linearize_lightness() {
+    for (space, tolerance) in [(LabSpace::Oklab, 2e-3), (LabSpace::Cielab, 0.2)] {
+        let g = CustomGradient::new()
+            .html_colors(&["#000", "#444", "#bbb", "#fff"])
+            .build()
+            .unwrap();
+        let before: Vec<f64> = g.colors(51).iter().map(|c| space.lightness(c)).collect();
+        assert!(!is_linear(&before, tolerance));
+
+        let result = g.linearize_lightness(space);
+        let g = &result.gradient;
+        let after: Vec<f64> = g.colors(51).iter().map(|c| space.lightness(c)).collect();
+
+        assert!(is_linear(&after, tolerance));
+        assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 0, 255]);
+        assert_eq!(g.at(1.0).to_rgba8(), [255, 255, 255, 255]);
+        assert_eq!(g.at(-1.0).to_rgba8(), [0, 0, 0, 255]);
+        assert_eq!(g.at(2.0).to_rgba8(), [255, 255, 255, 255]);
+
+        // Report: one entry for every stop, the stop colors are only moved
+        let positions: Vec<f64> = result.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions, [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+        assert_eq!(result.stops[0].new_position, 0.0);
+        assert_eq!(result.stops[3].new_position, 1.0);
+        assert!(result.stops[0].delta_e < 1e-6);
+        assert!(result.stops[3].delta_e < 1e-6);
+        assert!(result.max_shift() > 0.05);
+        assert!(result.max_delta_e() > 0.0);
+
+        for s in &result.stops {
+            assert_eq!(
+                g.at(s.new_position).to_rgba8(),
+                s.original.to_rgba8(),
+                "{:?}",
+                s
+            );
+            assert_eq!(s.adjusted.to_rgba8(), g.at(s.position).to_rgba8());
+            let dl = space.lightness(&s.adjusted) - space.lightness(&s.original);
+            assert!((s.lightness - dl).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn linearize_lightness_diverging() {
+    // Both ends have about the same lightness, the lightness goes up and down
+    let g = colorgrad::rd_bu();
+    let result = g.linearize_lightness(LabSpace::Oklab);
+    assert_eq!(result.stops.len(), 11);
+
+    let l: Vec<f64> = result
+        .gradient
+        .colors(101)
+        .iter()
+        .map(|c| LabSpace::Oklab.lightness(c))
+        .collect();
+    let steps: Vec<f64> = l.windows(2).map(|w| (w[1] - w[0]).abs()).collect();
+    let mean = steps.iter().sum::<f64>() / steps.len() as f64;
+    assert!(mean > 1e-3);
+
+    // Constant rate of change, except at the turning point
+    let uneven = steps
+        .iter()
+        .filter(|d| (*d - mean).abs() > 0.1 * mean)
+        .count();
+    assert!(uneven <= 2, "{:?}", steps);
+
+    // Up to the lightest color, then down
+    let peak = (0..l.len()).fold(0, |i, j| if l[j] > l[i] { j } else { i });
+    assert!(l[..=peak].windows(2).all(|w| w[1] > w[0]));
+    assert!(l[peak..].windows(2).all(|w| w[1] < w[0]));
+
+    for s in &result.stops {
+        let c = result.gradient.at(s.new_position);
+        assert!(DeltaE::Oklab.distance(&c, &s.original) < 2e-3);
+    }
+}
+
+#[test]
+fn linearize_lightness_unchanged() {
+    // Constant lightness
+    let g = CustomGradient::new()
+        .html_colors(&["#808080", "#808080"])
+        .build()
+        .unwrap();
+    let result = g.linearize_lightness(LabSpace::Oklab);
+    assert_eq!(result.max_shift(), 0.0);
+    assert_eq!(result.max_delta_e(), 0.0);
+
+    // Constant lightness, except for rounding
+    let g = colorgrad::phase_wheel();
+    let result = colorgrad::phase_wheel().linearize_lightness(LabSpace::Oklab);
+    for t in [0.0, 0.2, 0.5, 0.9] {
+        assert_eq!(result.gradient.at(t), g.at(t));
+    }
+
+    // Gradients without color stops report both ends
+    let result = colorgrad::turbo().linearize_lightness(LabSpace::Cielab);
+    assert_eq!(result.stops.len(), 2);
+    assert_eq!(result.stops[0].new_position, 0.0);
+    assert_eq!(result.stops[1].new_position, 1.0);
+}
@@ -0,0 +1,123 @@
+use colorgrad::{parse_ggr, Color, CustomGradient, DeltaE, Gradient};
+use std::fs::File;
+use std::io::BufReader;
+
+fn check_roundtrip(g: &Gradient, metric: DeltaE, tolerance: f64) {
+    let (dmin, dmax) = g.domain();
+    let index = g.position_index(metric, None);
+
+    for i in 0..=20 {
+        let t = dmin + (dmax - dmin) * i as f64 / 20.0;
+        let col = g.at(t);
+        let (pos, d) = index.position_of(&col).unwrap();
+
+        // Same color, but maybe at another position
+        assert!(d < tolerance, "t={} pos={} d={}", t, pos, d);
+        assert!((metric.distance(&g.at(pos), &col) - d).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn position_of_custom_gradient() {
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#ff0", "#00f"])
+        .domain(&[-10.0, 0.0, 10.0])
+        .build()
+        .unwrap();
+
+    for (metric, tolerance) in [(DeltaE::Oklab, 1e-4), (DeltaE::Ciede2000, 1e-2)] {
+        check_roundtrip(&g, metric, tolerance);
+
+        let (t, d) = g
+            .position_of(&Color::new(1.0, 0.0, 0.0, 1.0), metric, None)
+            .unwrap();
+        assert!((t - -10.0).abs() < 1e-6);
+        assert!(d < 1e-6);
+
+        let (t, _) = g
+            .position_of(&Color::new(1.0, 0.5, 0.0, 1.0), metric, None)
+            .unwrap();
+        assert!((t - -5.0).abs() < 1e-3);
+
+        let (t, _) = g
+            .position_of(&Color::new(0.0, 0.0, 1.0, 1.0), metric, None)
+            .unwrap();
+        assert!((t - 10.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn position_of_max_distance() {
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#fff"])
+        .build()
+        .unwrap();
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+
+    let (_, d) = g.position_of(&red, DeltaE::Oklab, None).unwrap();
+    assert!(d > 0.2);
+    assert_eq!(g.position_of(&red, DeltaE::Oklab, Some(0.2)), None);
+    assert!(g.position_of(&red, DeltaE::Oklab, Some(d + 1e-6)).is_some());
+
+    // Gray with the same lightness as red
+    let (t, d) = g.position_of(&red, DeltaE::Oklab, None).unwrap();
+    let (l, _, _, _) = red.to_oklaba();
+    let (l2, _, _, _) = g.at(t).to_oklaba();
+    assert!((l - l2).abs() < 1e-3);
+    assert!(d < red.to_oklaba().1.hypot(red.to_oklaba().2) + 1e-3);
+
+    let nan = Color::new(f64::NAN, 0.0, 0.0, 1.0);
+    assert_eq!(g.position_of(&nan, DeltaE::Oklab, None), None);
+}
+
+#[test]
+fn position_of_presets() {
+    let presets = [
+        colorgrad::viridis(),
+        colorgrad::rainbow(),
+        colorgrad::cubehelix_default(),
+        colorgrad::rd_yl_bu(),
+    ];
+
+    for g in presets {
+        check_roundtrip(&g, DeltaE::Oklab, 1e-4);
+    }
+
+    // Quantized to 8-bit, the exact color may fall between two samples
+    check_roundtrip(&colorgrad::turbo(), DeltaE::Oklab, 5e-3);
+    check_roundtrip(&colorgrad::cividis(), DeltaE::Oklab, 5e-3);
+
+    // Monotone maps have a unique position for every color
+    let g = colorgrad::viridis();
+    let index = g.position_index(DeltaE::Ciede2000, Some(1.0));
+    for t in [0.0, 0.123, 0.5, 0.877, 1.0] {
+        let (pos, _) = index.position_of(&g.at(t)).unwrap();
+        assert!((pos - t).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn position_of_ggr() {
+    let col = Color::default();
+    let f = File::open("examples/ggr/Abstract_1.ggr").unwrap();
+    let (g, _) = parse_ggr(BufReader::new(f), &col, &col).unwrap();
+    check_roundtrip(&g, DeltaE::Oklab, 1e-4);
+}
+
+#[test]
+fn position_of_hard_stops() {
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#f00", "#00f", "#00f"])
+        .domain(&[0.0, 0.5, 0.5, 1.0])
+        .build()
+        .unwrap();
+
+    let (t, _) = g
+        .position_of(&Color::new(1.0, 0.0, 0.0, 1.0), DeltaE::Oklab, None)
+        .unwrap();
+    assert!(t < 0.5);
+    let (t, _) = g
+        .position_of(&Color::new(0.0, 0.0, 1.0, 1.0), DeltaE::Oklab, None)
+        .unwrap();
+    assert!(t >= 0.5);
+}
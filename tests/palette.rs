@@ -0,0 +1,96 @@
+use colorgrad::{Color, Overflow, Palette};
+
+#[test]
+fn palette_index() {
+    let p = Palette::from_html(&["red", "lime", "blue"]).unwrap();
+    assert_eq!(p.len(), 3);
+    assert!(!p.is_empty());
+    assert_eq!(p.overflow(), Overflow::Wrap);
+
+    assert_eq!(p[0].to_rgba8(), [255, 0, 0, 255]);
+    assert_eq!(p[2].to_rgba8(), [0, 0, 255, 255]);
+    assert_eq!(p[3].to_rgba8(), [255, 0, 0, 255]);
+    assert_eq!(p[7].to_rgba8(), [0, 255, 0, 255]);
+
+    let p = p.with_overflow(Overflow::Clamp);
+    assert_eq!(p[1].to_rgba8(), [0, 255, 0, 255]);
+    assert_eq!(p[3].to_rgba8(), [0, 0, 255, 255]);
+    assert_eq!(p[100].to_rgba8(), [0, 0, 255, 255]);
+
+    let p = Palette::new(&[]);
+    assert!(p.is_empty());
+    assert_eq!(p.get(0), None);
+    assert!(p.sample(3).is_empty());
+
+    assert!(Palette::from_html(&["#ff0000", "#zzz"]).is_err());
+}
+
+#[test]
+fn palette_sample() {
+    let p = Palette::set1();
+
+    let colors = p.sample(5);
+    assert_eq!(colors.len(), 5);
+    assert_eq!(&colors[..], &p.colors()[..5]);
+
+    let colors = p.sample(11);
+    assert_eq!(colors[9], p[0]);
+    assert_eq!(colors[10], p[1]);
+
+    let colors = p.with_overflow(Overflow::Clamp).sample(11);
+    assert_eq!(colors[9].to_hex_string(), "#999999");
+    assert_eq!(colors[10].to_hex_string(), "#999999");
+}
+
+#[test]
+fn palette_gradient() {
+    let p = Palette::new(&[
+        Color::from_rgba8(255, 0, 0, 255),
+        Color::from_rgba8(0, 255, 0, 255),
+        Color::from_rgba8(0, 0, 255, 255),
+        Color::from_rgba8(255, 255, 0, 255),
+    ]);
+    let g = p.to_gradient();
+
+    assert_eq!(g.domain(), (0.0, 1.0));
+    assert_eq!(g.at(0.0).to_rgba8(), [255, 0, 0, 255]);
+    assert_eq!(g.at(0.24).to_rgba8(), [255, 0, 0, 255]);
+    assert_eq!(g.at(0.25).to_rgba8(), [0, 255, 0, 255]);
+    assert_eq!(g.at(0.6).to_rgba8(), [0, 0, 255, 255]);
+    assert_eq!(g.at(1.0).to_rgba8(), [255, 255, 0, 255]);
+
+    // No interpolated colors
+    for c in g.colors(50) {
+        assert!(p.colors().contains(&c));
+    }
+}
+
+#[test]
+fn palette_schemes() {
+    let schemes = [
+        (Palette::accent(), 8, "#7fc97f"),
+        (Palette::dark2(), 8, "#1b9e77"),
+        (Palette::paired(), 12, "#a6cee3"),
+        (Palette::pastel1(), 9, "#fbb4ae"),
+        (Palette::pastel2(), 8, "#b3e2cd"),
+        (Palette::set1(), 9, "#e41a1c"),
+        (Palette::set2(), 8, "#66c2a5"),
+        (Palette::set3(), 12, "#8dd3c7"),
+        (Palette::tableau10(), 10, "#4e79a7"),
+        (Palette::tableau20(), 20, "#1f77b4"),
+        (Palette::category10(), 10, "#1f77b4"),
+        (Palette::okabe_ito(), 8, "#000000"),
+    ];
+
+    for (p, n, first) in schemes {
+        assert_eq!(p.len(), n);
+        assert_eq!(p[0].to_hex_string(), first);
+
+        // All colors are distinct
+        for (i, a) in p.colors().iter().enumerate() {
+            for b in &p.colors()[i + 1..] {
+                assert_ne!(a.to_rgba8(), b.to_rgba8());
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use colorgrad::brewer::{self, Suitability};
+use colorgrad::{CustomGradient, PresetInfo, PresetKind, RegisterPresetError};
+
+#[test]
+fn preset_by_name() {
+    let g = colorgrad::preset_by_name("viridis").unwrap();
+    assert_eq!(
+        g.at(0.5).to_rgba8(),
+        colorgrad::viridis().at(0.5).to_rgba8()
+    );
+
+    for name in ["rd_yl_bu", "RdYlBu", "rd-yl-bu", "RD_YL_BU"] {
+        let g = colorgrad::preset_by_name(name).unwrap();
+        assert_eq!(
+            g.at(0.3).to_rgba8(),
+            colorgrad::rd_yl_bu().at(0.3).to_rgba8()
+        );
+    }
+
+    let g = colorgrad::preset_by_name("set1").unwrap();
+    assert_eq!(g.at(0.0).to_hex_string(), "#e41a1c");
+    assert_eq!(g.at(1.0).to_hex_string(), "#999999");
+
+    assert!(colorgrad::preset_by_name("").is_none());
+    assert!(colorgrad::preset_by_name("not_a_preset").is_none());
+}
+
+#[test]
+fn preset_list() {
+    let list = colorgrad::presets();
+
+    for info in list.iter() {
+        assert!(colorgrad::preset_by_name(&info.name).is_some());
+        assert_eq!(colorgrad::preset_info(&info.name).as_ref(), Some(info));
+        assert!(!info.source.is_empty());
+    }
+
+    let info = colorgrad::preset_info("sinebow").unwrap();
+    assert_eq!(info.kind, PresetKind::Cyclic);
+    assert!(colorgrad::preset_by_name("sinebow").unwrap().is_cyclic());
+
+    let info = colorgrad::preset_info("rd_bu").unwrap();
+    assert_eq!(info.kind, PresetKind::Diverging);
+    assert_eq!(info.source, "ColorBrewer");
+
+    let info = colorgrad::preset_info("okabe_ito").unwrap();
+    assert_eq!(info.kind, PresetKind::Qualitative);
+    assert!(info.colorblind_safe);
+
+    // Cyclic presets have the same color at both ends, seamless ones are flagged
+    for info in list.iter() {
+        let g = colorgrad::preset_by_name(&info.name).unwrap();
+        if info.kind == PresetKind::Cyclic {
+            assert_eq!(g.at(0.0), g.at(1.0), "{}", info.name);
+        } else {
+            assert!(!g.is_cyclic(), "{}", info.name);
+        }
+    }
+    assert!(!colorgrad::preset_by_name("rainbow").unwrap().is_cyclic());
+
+    // Same colorblind rating as the largest ColorBrewer scheme
+    for info in list.iter().filter(|i| i.source == "ColorBrewer") {
+        let n = *brewer::classes(&info.name.replace('_', "")).unwrap().end();
+        let s = brewer::scheme(&info.name.replace('_', ""), n).unwrap();
+        assert_eq!(
+            info.colorblind_safe,
+            s.colorblind_safe == Suitability::Yes,
+            "{}",
+            info.name
+        );
+    }
+}
+
+#[test]
+fn register_preset() {
+    let mut info = PresetInfo::new("Test Ocean", PresetKind::Sequential);
+    info.source = "test".to_string();
+    info.colorblind_safe = true;
+
+    colorgrad::register_preset(info.clone(), || {
+        CustomGradient::new()
+            .html_colors(&["#000080", "#00ffff"])
+            .build()
+            .unwrap()
+    })
+    .unwrap();
+
+    let g = colorgrad::preset_by_name("test_ocean").unwrap();
+    assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 128, 255]);
+    assert_eq!(colorgrad::preset_info("test-ocean"), Some(info.clone()));
+    assert!(colorgrad::presets().contains(&info));
+
+    // Replace
+    colorgrad::register_preset(info, || {
+        CustomGradient::new()
+            .html_colors(&["#800000", "#ffff00"])
+            .build()
+            .unwrap()
+    })
+    .unwrap();
+    let g = colorgrad::preset_by_name("test_ocean").unwrap();
+    assert_eq!(g.at(0.0).to_rgba8(), [128, 0, 0, 255]);
+    assert_eq!(
+        colorgrad::presets()
+            .iter()
+            .filter(|i| i.name == "Test Ocean")
+            .count(),
+        1
+    );
+
+    let err = colorgrad::register_preset(
+        PresetInfo::new("Viridis", PresetKind::Sequential),
+        colorgrad::magma,
+    );
+    assert_eq!(err, Err(RegisterPresetError::BuiltinName("viridis".into())));
+
+    let err = colorgrad::register_preset(
+        PresetInfo::new(" _ ", PresetKind::Sequential),
+        colorgrad::magma,
+    );
+    assert_eq!(err, Err(RegisterPresetError::EmptyName));
+}
+
+#[test]
+fn register_preset_reentrant() {
+    // Constructors may use the registry themselves
+    let info = PresetInfo::new("test_reentrant", PresetKind::Sequential);
+    colorgrad::register_preset(info, || {
+        let info = PresetInfo::new("test_reentrant_inner", PresetKind::Sequential);
+        colorgrad::register_preset(info, colorgrad::viridis).unwrap();
+        colorgrad::preset_by_name("test_reentrant_inner")
+            .unwrap()
+            .reversed()
+    })
+    .unwrap();
+
+    let g = colorgrad::preset_by_name("test_reentrant").unwrap();
+    assert_eq!(
+        g.at(0.0).to_rgba8(),
+        colorgrad::viridis().at(1.0).to_rgba8()
+    );
+    assert!(colorgrad::preset_info("test_reentrant_inner").is_some());
+}
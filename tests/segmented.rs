@@ -0,0 +1,123 @@
+use colorgrad::{BlendMode, SegmentedGradient};
+
+#[test]
+fn segmented_gradient() {
+    // matplotlib's "jet" colormap
+    let g = SegmentedGradient::new()
+        .red(&[
+            (0.0, 0.0, 0.0),
+            (0.35, 0.0, 0.0),
+            (0.66, 1.0, 1.0),
+            (0.89, 1.0, 1.0),
+            (1.0, 0.5, 0.5),
+        ])
+        .green(&[
+            (0.0, 0.0, 0.0),
+            (0.125, 0.0, 0.0),
+            (0.375, 1.0, 1.0),
+            (0.64, 1.0, 1.0),
+            (0.91, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+        ])
+        .blue(&[
+            (0.0, 0.5, 0.5),
+            (0.11, 1.0, 1.0),
+            (0.34, 1.0, 1.0),
+            (0.65, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+        ])
+        .build()
+        .unwrap();
+
+    assert_eq!(g.domain(), (0.0, 1.0));
+    assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 128, 255]);
+    assert_eq!(g.at(0.35).to_rgba8(), [0, 229, 247, 255]);
+    assert_eq!(g.at(0.5).to_rgba8(), [123, 255, 123, 255]);
+    assert_eq!(g.at(1.0).to_rgba8(), [128, 0, 0, 255]);
+    assert_eq!(g.at(-1.0).to_rgba8(), [0, 0, 128, 255]);
+    assert_eq!(g.at(2.0).to_rgba8(), [128, 0, 0, 255]);
+    assert_eq!(g.at(f64::NAN).to_rgba8(), [0, 0, 0, 255]);
+}
+
+#[test]
+fn segmented_gradient_discontinuity() {
+    let g = SegmentedGradient::new()
+        .red(&[(0.0, 0.0, 0.0), (0.5, 1.0, 0.0), (1.0, 1.0, 1.0)])
+        .green(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)])
+        .blue(&[(0.0, 1.0, 1.0), (0.5, 1.0, 0.0), (1.0, 0.0, 0.0)])
+        .alpha(&[(0.0, 1.0, 1.0), (0.5, 1.0, 0.5), (1.0, 1.0, 1.0)])
+        .domain(&[0.0, 100.0])
+        .build()
+        .unwrap();
+
+    assert_eq!(g.domain(), (0.0, 100.0));
+    assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 255, 255]);
+    assert_eq!(g.at(25.0).to_rgba8(), [128, 0, 255, 255]);
+    assert_eq!(g.at(50.0).to_rgba8(), [255, 0, 255, 255]);
+    assert_eq!(g.at(50.001).to_rgba8(), [0, 0, 0, 128]);
+    assert_eq!(g.at(75.0).to_rgba8(), [128, 0, 0, 191]);
+    assert_eq!(g.at(100.0).to_rgba8(), [255, 0, 0, 255]);
+}
+
+#[test]
+fn segmented_gradient_mode() {
+    let g = SegmentedGradient::new()
+        .red(&[(0.0, 0.0, 0.0), (1.0, 360.0, 360.0)])
+        .green(&[(0.0, 1.0, 1.0), (1.0, 1.0, 1.0)])
+        .blue(&[(0.0, 1.0, 1.0), (1.0, 1.0, 1.0)])
+        .mode(BlendMode::Hsv)
+        .build()
+        .unwrap();
+
+    assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(1.0 / 3.0).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(2.0 / 3.0).to_hex_string(), "#0000ff");
+    assert_eq!(g.at(1.0).to_hex_string(), "#ff0000");
+
+    let g = SegmentedGradient::new()
+        .red(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .green(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)])
+        .blue(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)])
+        .mode(BlendMode::Oklab)
+        .build()
+        .unwrap();
+
+    assert_eq!(g.at(0.0).to_hex_string(), "#000000");
+    assert_eq!(g.at(1.0).to_hex_string(), "#ffffff");
+}
+
+#[test]
+fn segmented_gradient_error() {
+    let g = SegmentedGradient::new()
+        .red(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .green(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .build();
+    assert_eq!(g.unwrap_err().to_string(), "missing channel");
+
+    let g = SegmentedGradient::new()
+        .red(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .green(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .blue(&[(0.0, 0.0, 0.0), (0.9, 1.0, 1.0)])
+        .build();
+    assert_eq!(g.unwrap_err().to_string(), "wrong domain");
+
+    let g = SegmentedGradient::new()
+        .red(&[
+            (0.0, 0.0, 0.0),
+            (0.6, 0.0, 0.0),
+            (0.5, 0.0, 0.0),
+            (1.0, 1.0, 1.0),
+        ])
+        .green(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .blue(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .build();
+    assert_eq!(g.unwrap_err().to_string(), "wrong domain");
+
+    let g = SegmentedGradient::new()
+        .red(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .green(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .blue(&[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)])
+        .domain(&[1.0, 0.0])
+        .build();
+    assert_eq!(g.unwrap_err().to_string(), "wrong domain");
+}
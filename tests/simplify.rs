@@ -0,0 +1,140 @@
+use colorgrad::{parse_ggr, BlendMode, Color, CustomGradient, DeltaE, Gradient, Interpolation};
+use std::fs::File;
+use std::io::BufReader;
+
+// Largest difference between the two gradients at n evenly spaced positions
+fn max_error(a: &Gradient, b: &Gradient, n: usize) -> f64 {
+    a.colors(n)
+        .iter()
+        .zip(b.colors(n).iter())
+        .map(|(x, y)| DeltaE::Oklab.distance(x, y).max((x.a - y.a).abs()))
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn linear_stops_of_linear_gradient() {
+    for mode in [BlendMode::Rgb, BlendMode::LinearRgb, BlendMode::Oklab] {
+        let g = CustomGradient::new()
+            .html_colors(&["#f00", "#ff0", "#00f"])
+            .domain(&[10.0, 30.0, 50.0])
+            .mode(mode)
+            .build()
+            .unwrap();
+
+        let s = g.to_linear_stops(0.005, mode).build().unwrap();
+        assert_eq!(s.domain(), (10.0, 50.0));
+        assert!(max_error(&g, &s, 1001) < 0.005);
+
+        // The original stops are enough
+        let cg = g.to_linear_stops(0.005, mode);
+        assert_eq!(cg.get_colors().len(), 3);
+        assert_eq!(cg.get_domain().len(), 3);
+        assert_eq!(cg.get_domain()[0], 10.0);
+        assert_eq!(cg.get_domain()[2], 50.0);
+    }
+}
+
+#[test]
+fn linear_stops_tolerance() {
+    let presets = [
+        colorgrad::turbo(),
+        colorgrad::cubehelix_default(),
+        colorgrad::sinebow(),
+        colorgrad::viridis(),
+    ];
+
+    for g in presets {
+        let mut count = usize::MAX;
+        for max_delta_e in [0.002, 0.01, 0.05] {
+            for mode in [BlendMode::Rgb, BlendMode::Oklab] {
+                let s = g.to_linear_stops(max_delta_e, mode).build().unwrap();
+                assert_eq!(s.domain(), g.domain());
+                assert_eq!(s.at(0.0).to_rgba8(), g.at(0.0).to_rgba8());
+                assert_eq!(s.at(1.0).to_rgba8(), g.at(1.0).to_rgba8());
+                // Checked only at the sample positions, allow a little more in between
+                assert!(max_error(&g, &s, 2001) < max_delta_e * 1.5);
+            }
+
+            let stops = stop_count(&g, max_delta_e);
+            assert!(stops < count);
+            count = stops;
+        }
+    }
+}
+
+fn stop_count(g: &Gradient, max_delta_e: f64) -> usize {
+    g.to_linear_stops(max_delta_e, BlendMode::Rgb)
+        .get_colors()
+        .len()
+}
+
+#[test]
+fn linear_stops_ggr_and_alpha() {
+    let col = Color::default();
+    let f = File::open("examples/ggr/Abstract_1.ggr").unwrap();
+    let (g, _) = parse_ggr(BufReader::new(f), &col, &col).unwrap();
+    let s = g.to_linear_stops(0.01, BlendMode::Rgb).build().unwrap();
+    assert!(max_error(&g, &s, 2001) < 0.015);
+
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#0f0", "#00f"])
+        .interpolation(Interpolation::Basis)
+        .opacity(&[(0.0, 1.0), (0.5, 0.0), (1.0, 1.0)])
+        .build()
+        .unwrap();
+    let s = g
+        .to_linear_stops(0.01, BlendMode::LinearRgb)
+        .build()
+        .unwrap();
+    assert!(max_error(&g, &s, 2001) < 0.015);
+}
+
+#[test]
+fn linear_stops_small_tolerance() {
+    // Many samples per stop, the search for the end of a stop must not be quadratic
+    let start = std::time::Instant::now();
+    let g = colorgrad::turbo();
+    let s = g.to_linear_stops(1e-3, BlendMode::Oklab).build().unwrap();
+    assert!(max_error(&g, &s, 2001) < 1e-3);
+    assert!(start.elapsed().as_secs() < 10);
+}
+
+#[test]
+fn linear_stops_not_monotone() {
+    // Gray in Oklab, lightness rises linearly, a little higher in [0.1, 0.2] and
+    // a little lower in [0.3, 0.5]. A stop from 0 to the lower part is tilted and
+    // misses the higher part, but a stop from 0 to 1 fits both, so whether a
+    // segment fits is not monotone in its end.
+    let e = 0.02;
+    let bumps = [
+        (0.0, 0.0),
+        (0.09, 0.0),
+        (0.1, 0.9 * e),
+        (0.2, 0.9 * e),
+        (0.21, 0.0),
+        (0.29, 0.0),
+        (0.3, -0.9 * e),
+        (0.5, -0.9 * e),
+        (0.51, 0.0),
+        (1.0, 0.0),
+    ];
+    let colors: Vec<Color> = bumps
+        .iter()
+        .map(|&(t, d)| Color::from_oklaba(0.2 + 0.6 * t + d, 0.0, 0.0, 1.0))
+        .collect();
+    let positions: Vec<f64> = bumps.iter().map(|b| b.0).collect();
+    let g = CustomGradient::new()
+        .colors(&colors)
+        .domain(&positions)
+        .mode(BlendMode::Oklab)
+        .build()
+        .unwrap();
+
+    let cg = g.to_linear_stops(e, BlendMode::Oklab);
+    // The search is greedy, it may end a stop early (at 0.29 instead of 1.0) but the
+    // stops still fit
+    assert!(cg.get_colors().len() <= 4);
+    let s = cg.build().unwrap();
+    assert_eq!(s.domain(), (0.0, 1.0));
+    assert!(max_error(&g, &s, 2001) < e * 1.5);
+}
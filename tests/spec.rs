@@ -0,0 +1,132 @@
+use colorgrad::{Gradient, ParseColorError, ParseSpecError};
+
+#[test]
+fn spec_presets() {
+    let g = colorgrad::parse_spec("viridis").unwrap();
+    assert_eq!(
+        g.at(0.3).to_rgba8(),
+        colorgrad::viridis().at(0.3).to_rgba8()
+    );
+
+    let g: Gradient = "viridis_r".parse().unwrap();
+    assert_eq!(g.at(0.0).to_hex_string(), "#fee825");
+    assert_eq!(g.at(1.0).to_hex_string(), "#440154");
+
+    let g: Gradient = "RdBu; reverse".parse().unwrap();
+    assert_eq!(g.at(0.2).to_rgba8(), colorgrad::rd_bu().at(0.8).to_rgba8());
+
+    let g: Gradient = "rd_bu_r; sharp(5)".parse().unwrap();
+    let h = colorgrad::rd_bu().reversed().sharp(5, 0.0);
+    for t in [0.0, 0.15, 0.5, 0.71, 1.0] {
+        assert_eq!(g.at(t).to_rgba8(), h.at(t).to_rgba8());
+    }
+}
+
+#[test]
+fn spec_colors() {
+    let g = colorgrad::parse_spec("red, lime, blue").unwrap();
+    assert_eq!(g.domain(), (0.0, 1.0));
+    assert_eq!(g.at(0.5).to_hex_string(), "#00ff00");
+
+    let g = colorgrad::parse_spec("red 0%, gold 30%, #00f").unwrap();
+    assert_eq!(g.at(0.0).to_hex_string(), "#ff0000");
+    assert_eq!(g.at(0.3).to_hex_string(), "#ffd700");
+    assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+
+    // Missing positions are spread between their neighbours
+    let g = colorgrad::parse_spec("red 20, lime, blue, white 80").unwrap();
+    assert_eq!(g.domain(), (20.0, 80.0));
+    assert_eq!(g.at(40.0).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(60.0).to_hex_string(), "#0000ff");
+
+    // Double position, and positions less than the previous are clamped
+    let g = colorgrad::parse_spec("red, lime 40% 60%, blue 10%").unwrap();
+    assert_eq!(g.at(0.5).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(0.59).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(0.61).to_hex_string(), "#0000ff");
+
+    // All colors at the same position
+    let g = colorgrad::parse_spec("red 1, blue 1").unwrap();
+    assert_eq!(g.domain(), (1.0, 1.0));
+    assert_eq!(g.at(2.0).to_hex_string(), "#0000ff");
+
+    // Commas inside functions
+    let g = colorgrad::parse_spec("rgb(255, 0, 0), hsl(240, 100%, 50%) 100%").unwrap();
+    assert_eq!(g.at(1.0).to_hex_string(), "#0000ff");
+
+    let g = colorgrad::parse_spec("#000, #fff; mode=linear-rgb").unwrap();
+    let h = colorgrad::CustomGradient::new()
+        .html_colors(&["#000", "#fff"])
+        .mode(colorgrad::BlendMode::LinearRgb)
+        .build()
+        .unwrap();
+    assert_eq!(g.at(0.5).to_rgba8(), h.at(0.5).to_rgba8());
+
+    let g = colorgrad::parse_spec("red, lime, blue; interpolation=step; reverse").unwrap();
+    assert_eq!(g.at(0.1).to_hex_string(), "#00ff00");
+    assert_eq!(g.at(0.6).to_hex_string(), "#ff0000");
+
+    // Spaces around '='
+    let g = colorgrad::parse_spec("#000, #fff; mode = linear-rgb").unwrap();
+    assert_eq!(g.at(0.5).to_rgba8(), h.at(0.5).to_rgba8());
+
+    let g = colorgrad::parse_spec("red, lime, blue; interpolation =step").unwrap();
+    assert_eq!(g.at(0.1).to_hex_string(), "#ff0000");
+}
+
+#[test]
+fn spec_errors() {
+    assert_eq!(
+        colorgrad::parse_spec("").unwrap_err(),
+        ParseSpecError::Empty
+    );
+    assert_eq!(
+        colorgrad::parse_spec(" ; reverse").unwrap_err(),
+        ParseSpecError::Empty
+    );
+    assert_eq!(
+        colorgrad::parse_spec("viridsi").unwrap_err(),
+        ParseSpecError::UnknownPreset("viridsi".to_string())
+    );
+    assert_eq!(
+        colorgrad::parse_spec("red, bleu").unwrap_err(),
+        ParseSpecError::InvalidColor("bleu".to_string(), ParseColorError::InvalidUnknown)
+    );
+    assert_eq!(
+        colorgrad::parse_spec("red, blue 50px").unwrap_err(),
+        ParseSpecError::InvalidPosition("50px".to_string())
+    );
+    assert_eq!(
+        colorgrad::parse_spec("red, blue 10% 20% 30%").unwrap_err(),
+        ParseSpecError::InvalidStop("blue 10% 20% 30%".to_string())
+    );
+    assert_eq!(
+        colorgrad::parse_spec("red, blue 10% 20% 30%")
+            .unwrap_err()
+            .to_string(),
+        "invalid color stop 'blue 10% 20% 30%'"
+    );
+    assert_eq!(
+        colorgrad::parse_spec("red, blue; smooth").unwrap_err(),
+        ParseSpecError::UnknownOption("smooth".to_string())
+    );
+    assert_eq!(
+        colorgrad::parse_spec("red, blue; mode=cmyk").unwrap_err(),
+        ParseSpecError::InvalidOption("mode=cmyk".to_string())
+    );
+    assert_eq!(
+        colorgrad::parse_spec("viridis; mode=oklab").unwrap_err(),
+        ParseSpecError::InvalidOption("mode=oklab".to_string())
+    );
+    assert_eq!(
+        colorgrad::parse_spec("viridis; mode = oklab").unwrap_err(),
+        ParseSpecError::InvalidOption("mode = oklab".to_string())
+    );
+    assert_eq!(
+        colorgrad::parse_spec("magma; sharp(x)").unwrap_err(),
+        ParseSpecError::InvalidOption("sharp(x)".to_string())
+    );
+
+    let err = "red, bleu".parse::<Gradient>().unwrap_err();
+    assert!(std::error::Error::source(&err).is_some());
+}
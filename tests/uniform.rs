@@ -0,0 +1,111 @@
+use colorgrad::{parse_ggr, Color, CustomGradient, DeltaE, Gradient};
+use std::fs::File;
+use std::io::BufReader;
+
+// Ratio of the largest to the smallest perceptual length of n equal sections,
+// each measured along the gradient with 100 samples
+fn step_ratio(g: &Gradient, n: usize, metric: DeltaE) -> f64 {
+    let colors = g.colors(n * 100 + 1);
+    let steps: Vec<f64> = colors
+        .windows(2)
+        .map(|c| metric.distance(&c[0], &c[1]))
+        .collect::<Vec<_>>()
+        .chunks(100)
+        .map(|c| c.iter().sum())
+        .collect();
+    let max = steps.iter().cloned().fold(f64::MIN, f64::max);
+    let min = steps.iter().cloned().fold(f64::MAX, f64::min);
+    max / min
+}
+
+#[test]
+fn delta_e() {
+    let black = Color::new(0.0, 0.0, 0.0, 1.0);
+    let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+
+    for metric in [DeltaE::Oklab, DeltaE::Ciede2000] {
+        assert_eq!(metric.distance(&red, &red), 0.0);
+        assert_eq!(metric.distance(&red, &white), metric.distance(&white, &red));
+        assert!(metric.distance(&black, &red) > 0.0);
+    }
+}
+
+#[test]
+fn uniformized_custom_gradient() {
+    let g = CustomGradient::new()
+        .html_colors(&["#000", "#888", "#fff"])
+        .domain(&[0.0, 0.1, 1.0])
+        .build()
+        .unwrap();
+    assert!(step_ratio(&g, 21, DeltaE::Oklab) > 5.0);
+
+    for metric in [DeltaE::Oklab, DeltaE::Ciede2000] {
+        let g = CustomGradient::new()
+            .html_colors(&["#000", "#888", "#fff"])
+            .domain(&[0.0, 0.1, 1.0])
+            .build()
+            .unwrap()
+            .uniformized(metric);
+
+        assert_eq!(g.domain(), (0.0, 1.0));
+        assert_eq!(g.at(0.0).to_rgba8(), [0, 0, 0, 255]);
+        assert_eq!(g.at(1.0).to_rgba8(), [255, 255, 255, 255]);
+        assert_eq!(g.at(-1.0).to_rgba8(), [0, 0, 0, 255]);
+        assert_eq!(g.at(2.0).to_rgba8(), [255, 255, 255, 255]);
+        assert!(step_ratio(&g, 21, metric) < 1.02);
+    }
+
+    // Domain other than [0..1]
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#ff0", "#00f"])
+        .domain(&[-10.0, -8.0, 10.0])
+        .build()
+        .unwrap()
+        .uniformized(DeltaE::Oklab);
+
+    assert_eq!(g.domain(), (-10.0, 10.0));
+    assert_eq!(g.at(-10.0).to_rgba8(), [255, 0, 0, 255]);
+    assert_eq!(g.at(10.0).to_rgba8(), [0, 0, 255, 255]);
+    assert!(step_ratio(&g, 21, DeltaE::Oklab) < 1.02);
+}
+
+#[test]
+fn uniformized_preset() {
+    for g in [
+        colorgrad::rainbow(),
+        colorgrad::sinebow(),
+        colorgrad::spectral(),
+    ] {
+        let first = g.at(0.0).to_rgba8();
+        let last = g.at(1.0).to_rgba8();
+        let g = g.uniformized(DeltaE::Oklab);
+
+        assert_eq!(g.at(0.0).to_rgba8(), first);
+        assert_eq!(g.at(1.0).to_rgba8(), last);
+        assert!(step_ratio(&g, 21, DeltaE::Oklab) < 1.02);
+    }
+}
+
+#[test]
+fn uniformized_ggr() {
+    let col = Color::default();
+    let f = File::open("examples/ggr/Abstract_1.ggr").unwrap();
+    let (g, _) = parse_ggr(BufReader::new(f), &col, &col).unwrap();
+    let g = g.uniformized(DeltaE::Oklab);
+
+    assert!(step_ratio(&g, 21, DeltaE::Oklab) < 1.02);
+}
+
+#[test]
+fn uniformized_constant() {
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#f00"])
+        .build()
+        .unwrap()
+        .uniformized(DeltaE::Oklab);
+
+    assert_eq!(g.at(0.0).to_rgba8(), [255, 0, 0, 255]);
+    assert_eq!(g.at(0.5).to_rgba8(), [255, 0, 0, 255]);
+    assert_eq!(g.at(1.0).to_rgba8(), [255, 0, 0, 255]);
+}
@@ -0,0 +1,107 @@
+use colorgrad::{BlendMode, ColorSpace, CustomGradient, WideColor};
+
+fn assert_close(a: [f64; 4], b: [f64; 4]) {
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((x - y).abs() < 1e-4, "{:?} != {:?}", a, b);
+    }
+}
+
+#[test]
+fn color_space_conversion() {
+    let red = WideColor::new(ColorSpace::Srgb, 1.0, 0.0, 0.0, 1.0);
+    assert!(red.in_gamut());
+
+    let c = red.to_space(ColorSpace::DisplayP3);
+    assert_eq!(c.space, ColorSpace::DisplayP3);
+    assert_close(c.to_array(), [0.9175, 0.2003, 0.1386, 1.0]);
+    assert!(c.in_gamut());
+
+    let c = red.to_space(ColorSpace::Rec2020);
+    assert_close(c.to_array(), [0.7920, 0.2310, 0.0738, 1.0]);
+
+    let c = red.to_space(ColorSpace::SrgbLinear);
+    assert_close(c.to_array(), [1.0, 0.0, 0.0, 1.0]);
+
+    let p3_red = WideColor::new(ColorSpace::DisplayP3, 1.0, 0.0, 0.0, 0.5);
+    let c = p3_red.to_space(ColorSpace::Srgb);
+    assert_close(c.to_array(), [1.0931, -0.2267, -0.1501, 0.5]);
+    assert!(!c.in_gamut());
+
+    let rec2020_green = WideColor::new(ColorSpace::Rec2020, 0.0, 1.0, 0.0, 1.0);
+    assert!(!rec2020_green.to_space(ColorSpace::DisplayP3).in_gamut());
+    assert!(!rec2020_green.to_space(ColorSpace::Srgb).in_gamut());
+
+    // Round trip
+    for space in [
+        ColorSpace::Srgb,
+        ColorSpace::SrgbLinear,
+        ColorSpace::DisplayP3,
+        ColorSpace::Rec2020,
+    ] {
+        for c in [
+            p3_red,
+            rec2020_green,
+            WideColor::new(ColorSpace::Srgb, 0.2, 0.5, 0.8, 1.0),
+            WideColor::new(ColorSpace::Srgb, 0.01, 0.001, 0.0, 1.0),
+        ] {
+            let c2 = c.to_space(space).to_space(c.space);
+            assert_close(c.to_array(), c2.to_array());
+        }
+    }
+}
+
+#[test]
+fn wide_gamut_gradient() {
+    let p3_red = WideColor::new(ColorSpace::DisplayP3, 1.0, 0.0, 0.0, 1.0);
+    let p3_green = WideColor::new(ColorSpace::DisplayP3, 0.0, 1.0, 0.0, 1.0);
+
+    for mode in [BlendMode::Rgb, BlendMode::LinearRgb, BlendMode::Oklab] {
+        let g = CustomGradient::new()
+            .wide_colors(&[p3_red, p3_green])
+            .mode(mode)
+            .build()
+            .unwrap();
+
+        assert!(!g.in_gamut());
+        assert_close(
+            g.at_in(0.0, ColorSpace::DisplayP3).to_array(),
+            [1.0, 0.0, 0.0, 1.0],
+        );
+        assert_close(
+            g.at_in(1.0, ColorSpace::DisplayP3).to_array(),
+            [0.0, 1.0, 0.0, 1.0],
+        );
+    }
+
+    // Linear blending in linear light is the same in every RGB color space
+    let g = CustomGradient::new()
+        .wide_colors(&[p3_red, p3_green])
+        .mode(BlendMode::LinearRgb)
+        .build()
+        .unwrap();
+    assert!(g.at_in(0.5, ColorSpace::DisplayP3).in_gamut());
+    assert!(g.at_in(0.5, ColorSpace::Rec2020).in_gamut());
+    let c = g.at_in(0.5, ColorSpace::DisplayP3).to_array();
+    let lin = |x: f64| ((x + 0.055) / 1.055).powf(2.4);
+    assert_close([lin(c[0]), lin(c[1]), c[2], c[3]], [0.5, 0.5, 0.0, 1.0]);
+
+    let colors = g.colors_in(3, ColorSpace::SrgbLinear);
+    assert_eq!(colors.len(), 3);
+    assert!(colors.iter().all(|c| c.space == ColorSpace::SrgbLinear));
+    assert_close(
+        colors[1].to_array(),
+        WideColor::new(ColorSpace::DisplayP3, c[0], c[1], c[2], 1.0)
+            .to_space(ColorSpace::SrgbLinear)
+            .to_array(),
+    );
+
+    // sRGB colors are unchanged
+    let g = CustomGradient::new()
+        .html_colors(&["#f00", "#00f"])
+        .build()
+        .unwrap();
+    assert_close(
+        g.at_in(0.0, ColorSpace::Srgb).to_array(),
+        [1.0, 0.0, 0.0, 1.0],
+    );
+}